@@ -0,0 +1,79 @@
+//! Adapters which implement the comparison traits in terms of arbitrary
+//! closures, so that the same invariants can be checked against comparators.
+
+use std::cmp::Ordering;
+
+/// Compares values with a comparator closure, e.g. one passed to
+/// [`slice::sort_by`].
+pub struct By<'a, T, F> {
+    pub value: &'a T,
+    pub cmp: &'a F,
+}
+
+impl<'a, T, F> PartialEq for By<'a, T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.cmp)(self.value, other.value) == Ordering::Equal
+    }
+}
+
+impl<'a, T, F> Eq for By<'a, T, F> where F: Fn(&T, &T) -> Ordering {}
+
+impl<'a, T, F> PartialOrd for By<'a, T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T, F> Ord for By<'a, T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.cmp)(self.value, other.value)
+    }
+}
+
+/// Compares values with a partial comparator closure.
+pub struct PartialBy<'a, T, F> {
+    pub value: &'a T,
+    pub partial_cmp: &'a F,
+}
+
+impl<'a, T, F> PartialEq for PartialBy<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<Ordering>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.partial_cmp)(self.value, other.value) == Some(Ordering::Equal)
+    }
+}
+
+impl<'a, T, F> PartialOrd for PartialBy<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<Ordering>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.partial_cmp)(self.value, other.value)
+    }
+}
+
+/// Compares values for equality with an equivalence relation closure.
+pub struct EqBy<'a, T, F> {
+    pub value: &'a T,
+    pub eq: &'a F,
+}
+
+impl<'a, T, F> PartialEq for EqBy<'a, T, F>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.eq)(self.value, other.value)
+    }
+}
@@ -1,5 +1,7 @@
 //! Crate error types.
 
+use std::fmt;
+
 use thiserror::Error;
 
 /// Represents a broken invariant of [`PartialEq`].
@@ -16,6 +18,23 @@ pub enum PartialEqError {
     /// `a == b && b == c` *MUST* imply `a == c`.
     #[error("a == b && b == c MUST imply a == c")]
     BrokeTransitivity,
+    /// `a == a` *MUST* be true. Only returned by
+    /// [`invariants::eq_reflexivity`](crate::invariants::eq_reflexivity);
+    /// the top-level checkers report [`EqError::BrokeReflexivity`] instead.
+    #[error("a == a MUST be true")]
+    BrokeReflexivity,
+    /// `a == b` *MUST* be equal to `key(a) == key(b)`. Only checked by
+    /// [`eq_matches_key`](crate::eq_matches_key).
+    #[error("a == b MUST be equal to key(a) == key(b)")]
+    DisagreesWithKey,
+    /// Repeated calls of [`PartialEq::eq`] *MUST* return the same result,
+    /// even when interleaved with other comparisons.
+    #[error("Repeated calls of a == b MUST return the same result")]
+    Impure,
+    /// `a == b` *MUST* be equal to `old_eq(a, b)`. Only checked by
+    /// [`partial_eq_agrees_with`](crate::partial_eq_agrees_with).
+    #[error("a == b MUST be equal to old_eq(a, b)")]
+    DisagreesWithOracle,
 }
 
 /// Represents a broken invariant of [`Eq`].
@@ -60,9 +79,56 @@ pub enum PartialOrdError {
     /// If `a > b`, then `b < a` *MUST* be true.
     #[error("If a > b, then b < a MUST be true")]
     BrokeDuality,
+    /// `a.partial_cmp(b)` *MUST* be [`None`] if and only if
+    /// `b.partial_cmp(a)` is [`None`].
+    #[error("a.partial_cmp(b) MUST be None if and only if b.partial_cmp(a) is None")]
+    BrokeIncomparabilitySymmetry,
+    /// `a.partial_cmp(b)` *MUST* be equal to
+    /// `b.partial_cmp(a).map(Ordering::reverse)`.
+    #[error("a.partial_cmp(b) MUST be equal to b.partial_cmp(a).map(Ordering::reverse)")]
+    BrokePartialCmpDuality,
+    /// If `a.partial_cmp(b)` is [`None`], then `a < b`, `a <= b`, `a > b`,
+    /// `a >= b`, and `a == b` *MUST* all be false.
+    #[error("If a.partial_cmp(b) is None, then a < b, a <= b, a > b, a >= b, and a == b MUST all be false")]
+    BadIncomparable,
     /// If `a > b` and `b > c`, then `a > c` *MUST* be true. The same must hold true for `<`.
     #[error("If a > b and b > c, then a > c MUST be true. The same must hold true for <")]
     BrokeTransitivity,
+    /// Transitivity *MUST* also hold for chains which mix `==`, `<`, and `<=`
+    /// (or `==`, `>`, and `>=`), e.g. `a < b && b == c` *MUST* imply `a < c`
+    /// and `a <= b && b < c` *MUST* imply `a < c`.
+    #[error("Chains mixing ==, < and <= (or ==, > and >=) MUST be transitive, e.g. a < b && b == c MUST imply a < c")]
+    BrokeMixedTransitivity,
+    /// `a < a` and `a > a` *MUST* be false.
+    #[error("a < a and a > a MUST be false")]
+    BrokeIrreflexivity,
+    /// If `a < b`, then `b < a` *MUST* be false. The same must hold true for `>`.
+    #[error("If a < b, then b < a MUST be false. The same must hold true for >")]
+    BrokeAsymmetry,
+    /// If `a <= b` and `b <= a`, then `a == b` *MUST* be true.
+    #[error("If a <= b and b <= a, then a == b MUST be true")]
+    BrokeAntisymmetry,
+    /// If neither `a < b` nor `b < a`, and neither `b < c` nor `c < b`, then
+    /// neither `a < c` nor `c < a` *MUST* be true.
+    ///
+    /// Note that [`PartialOrd`] alone does **not** require this, but sorting
+    /// algorithms do. See [`strict_weak_order`](crate::strict_weak_order).
+    #[error("If a and b are incomparable and b and c are incomparable, then a and c MUST be incomparable")]
+    BrokeIncomparabilityTransitivity,
+    /// `a.partial_cmp(b)` *MUST* be equal to `key(a).partial_cmp(&key(b))`.
+    /// Only checked by
+    /// [`partial_ord_matches_key`](crate::partial_ord_matches_key).
+    #[error("a.partial_cmp(b) MUST be equal to key(a).partial_cmp(&key(b))")]
+    DisagreesWithKey,
+    /// Repeated calls of [`PartialOrd::partial_cmp`] *MUST* return the same
+    /// result, even when interleaved with other comparisons.
+    #[error("Repeated calls of a.partial_cmp(b) MUST return the same result")]
+    Impure,
+    /// A chain of `<` and `==` *MUST NOT* lead back to where it started, e.g.
+    /// `a < b && b < c && c == d` implies `!(d < a)`. This is a consequence of
+    /// transitivity which can span any number of values.
+    #[error("A chain of < and == MUST NOT lead back to where it started")]
+    Cycle,
 }
 
 /// Represents a broken invariant of [`Ord`].
@@ -75,14 +141,53 @@ pub enum OrdError {
     #[error("`cmp` and `partial_cmp` are not consistent")]
     BadCmp,
     /// [`Ord::cmp`] and [`Ord::max`] are not consistent.
+    ///
+    /// No longer returned, see [`OrdError::MaxDiffersFromDefault`] instead.
     #[error("`cmp` and `max` are not consistent")]
     BadMax,
     /// [`Ord::cmp`] and [`Ord::min`] are not consistent.
+    ///
+    /// No longer returned, see [`OrdError::MinDiffersFromDefault`] instead.
     #[error("`cmp` and `min` are not consistent")]
     BadMin,
     /// [`Ord::cmp`] and [`Ord::clamp`] are not consistent.
+    ///
+    /// No longer returned, see [`OrdError::ClampDiffersFromDefault`] instead.
     #[error("`cmp` and `clamp` are not consistent")]
     BadClamp,
+    /// `a.cmp(b)` *MUST* be equal to `b.cmp(a).reverse()`.
+    #[error("a.cmp(b) MUST be equal to b.cmp(a).reverse()")]
+    BrokeCmpAntisymmetry,
+    /// [`Ord::max`] *MUST* return the same value as its default
+    /// implementation, i.e. `b` if `a.cmp(b)` is `Less` or `Equal`, and `a`
+    /// otherwise.
+    #[error("a.max(b) MUST return b if a.cmp(b) is Less or Equal, and a otherwise")]
+    MaxDiffersFromDefault,
+    /// [`Ord::min`] *MUST* return the same value as its default
+    /// implementation, i.e. `a` if `a.cmp(b)` is `Less` or `Equal`, and `b`
+    /// otherwise.
+    #[error("a.min(b) MUST return a if a.cmp(b) is Less or Equal, and b otherwise")]
+    MinDiffersFromDefault,
+    /// [`Ord::clamp`] *MUST* return the same value as its default
+    /// implementation, i.e. `min` if `a < min`, `max` if `a > max`, and `a`
+    /// otherwise.
+    #[error("a.clamp(min, max) MUST return min if a < min, max if a > max, and a otherwise")]
+    ClampDiffersFromDefault,
+    /// [`Ord::clamp`] *MUST* panic if `min > max`.
+    #[error("a.clamp(min, max) MUST panic if min > max")]
+    ClampDidNotPanic,
+    /// `a.cmp(b)` *MUST* be equal to `key(a).cmp(&key(b))`. Only checked by
+    /// [`ord_matches_key`](crate::ord_matches_key).
+    #[error("a.cmp(b) MUST be equal to key(a).cmp(&key(b))")]
+    DisagreesWithKey,
+    /// Repeated calls of [`Ord::cmp`] *MUST* return the same result, even when
+    /// interleaved with other comparisons.
+    #[error("Repeated calls of a.cmp(b) MUST return the same result")]
+    Impure,
+    /// `a.cmp(b)` *MUST* be equal to `old_cmp(a, b)`. Only checked by
+    /// [`ord_agrees_with`](crate::ord_agrees_with).
+    #[error("a.cmp(b) MUST be equal to old_cmp(a, b)")]
+    DisagreesWithOracle,
 }
 
 /// Represents a broken invariant of [`Hash`].
@@ -90,14 +195,177 @@ pub enum OrdError {
 #[non_exhaustive]
 pub enum HashError {
     /// Equal values *MUST* have equal hash values.
+    ///
+    /// [`hash_verbose`](crate::hash_verbose) and
+    /// [`hash_report`](crate::hash_report) also point to the first write at
+    /// which the hasher outputs diverge.
     #[error("Equal values MUST have equal hash values")]
     EqualButDifferentHashes,
+    /// Unequal values *SHOULD* have different hash values, i.e. they shouldn't
+    /// write exactly the same data to the hasher.
+    ///
+    /// Unlike the other variants, this is a quality issue rather than a
+    /// correctness bug: it only makes hash collisions more likely. See
+    /// [`HashError::is_quality_issue`].
+    #[error("Unequal values SHOULD have different hash values")]
+    UnequalButSameHashes,
     /// When two values are different (as defined by [`PartialEq::ne`]), neither
-    /// of the two hash outputs can be a prefix of the other. See
+    /// of the two hash outputs can be a proper prefix of the other. See
     /// <https://doc.rust-lang.org/std/hash/trait.Hash.html#prefix-collisions>
     /// for more information.
     #[error("When two values are different, one of the two hash outputs CAN NOT be a prefix of the other")]
     PrefixCollision,
+    /// When two sequences of values are different, the concatenations of their
+    /// hash outputs *MUST* be different too, e.g. `("ab", "c")` and `("a",
+    /// "bc")` can't write the same data to the hasher.
+    #[error("When two sequences of values are different, the concatenations of their hash outputs MUST be different")]
+    ConcatenationCollision,
+    /// Equal slices *MUST* have equal [`Hash::hash_slice`](std::hash::Hash::hash_slice)
+    /// outputs.
+    #[error("Equal slices MUST have equal T::hash_slice outputs")]
+    HashSliceEqualButDifferentHashes,
+    /// Unequal slices *SHOULD* have different
+    /// [`Hash::hash_slice`](std::hash::Hash::hash_slice) outputs.
+    ///
+    /// Like [`HashError::UnequalButSameHashes`], this is a quality issue
+    /// rather than a correctness bug. See [`HashError::is_quality_issue`].
+    #[error("Unequal slices SHOULD have different T::hash_slice outputs")]
+    HashSliceUnequalButSameHashes,
+    /// [`Hash::hash_slice`](std::hash::Hash::hash_slice) *SHOULD* write the
+    /// same data to the hasher as hashing each element in turn.
+    ///
+    /// Like [`HashError::UnequalButSameHashes`], this is a quality issue
+    /// rather than a correctness bug, as `std` leaves the output of
+    /// [`Hash::hash_slice`](std::hash::Hash::hash_slice) unspecified. See
+    /// [`HashError::is_quality_issue`].
+    #[error(
+        "T::hash_slice SHOULD write the same data to the hasher as hashing each element in turn"
+    )]
+    HashSliceDiffersFromElements,
+    /// Hashing the same value twice *MUST* write the same data to the hasher.
+    #[error("Hashing the same value twice MUST write the same data to the hasher")]
+    Nondeterministic,
+    /// Repeated calls of [`Hash::hash`](std::hash::Hash::hash) *MUST* write
+    /// the same data to the hasher, even when interleaved with comparisons.
+    #[error("Repeated calls of Hash::hash MUST write the same data to the hasher")]
+    Impure,
+    /// Hashing a clone, or an identically constructed copy, of a value *MUST*
+    /// write the same data to the hasher as the value itself, e.g. it can't
+    /// depend on pointer addresses or on the iteration order of a
+    /// [`HashSet`](std::collections::HashSet).
+    #[error(
+        "Hashing a clone or an identical copy of a value MUST write the same data to the hasher"
+    )]
+    DiffersFromCopy,
+    /// [`Hash::hash`](std::hash::Hash::hash) *MUST* write exactly the same
+    /// data to the hasher as `old_hash`. Only checked by
+    /// [`hash_agrees_with`](crate::hash_agrees_with).
+    #[error("Hash::hash MUST write the same data to the hasher as old_hash")]
+    DisagreesWithOracle,
+}
+
+impl HashError {
+    /// Returns [`true`] if this is a quality issue rather than a correctness
+    /// bug, i.e. [`HashError::UnequalButSameHashes`],
+    /// [`HashError::HashSliceUnequalButSameHashes`], or
+    /// [`HashError::HashSliceDiffersFromElements`].
+    ///
+    /// This can be passed to [`Report::downgrade`] to treat such issues as
+    /// warnings.
+    pub fn is_quality_issue(&self) -> bool {
+        matches!(
+            self,
+            Self::UnequalButSameHashes
+                | Self::HashSliceUnequalButSameHashes
+                | Self::HashSliceDiffersFromElements
+        )
+    }
+}
+
+/// Represents a [`Borrow`](std::borrow::Borrow) implementation which doesn't
+/// preserve the behavior of the key, as required by e.g.
+/// [`HashMap::get`](std::collections::HashMap::get) and
+/// [`BTreeMap::get`](std::collections::BTreeMap::get).
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum BorrowError {
+    /// `a == b` *MUST* be equal to `a.borrow() == b.borrow()`.
+    #[error("a == b MUST be equal to a.borrow() == b.borrow()")]
+    BadEq,
+    /// `a` and `a.borrow()` *MUST* write exactly the same data to the hasher.
+    #[error("a and a.borrow() MUST write the same data to the hasher")]
+    BadHash,
+    /// `a.cmp(b)` *MUST* be equal to `a.borrow().cmp(b.borrow())`.
+    #[error("a.cmp(b) MUST be equal to a.borrow().cmp(b.borrow())")]
+    BadCmp,
+}
+
+/// Represents an `Equivalent` implementation (as used by e.g. `hashbrown` and
+/// `indexmap` for lookups) which isn't coherent with the [`Hash`] and [`Eq`]
+/// implementations of the key.
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum EquivalentError {
+    /// If `q.equivalent(a)`, then `q` and `a` *MUST* write the same data to
+    /// the hasher.
+    #[error("If q.equivalent(a), then q and a MUST write the same data to the hasher")]
+    BadHash,
+    /// If `q.equivalent(a)`, then `q.equivalent(b)` *MUST* be equal to
+    /// `a == b`.
+    #[error("If q.equivalent(a), then q.equivalent(b) MUST be equal to a == b")]
+    BadEq,
+}
+
+/// Represents a function between ordered types which doesn't preserve (or
+/// reverse) their order.
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum MonotonicityError {
+    /// `a <= b` *MUST* imply `f(a) <= f(b)`.
+    #[error("a <= b MUST imply f(a) <= f(b)")]
+    NotMonotone,
+    /// `a < b` *MUST* imply `f(a) < f(b)`.
+    #[error("a < b MUST imply f(a) < f(b)")]
+    NotStrictlyMonotone,
+    /// `a <= b` *MUST* imply `f(a) >= f(b)`.
+    #[error("a <= b MUST imply f(a) >= f(b)")]
+    NotAntitone,
+}
+
+/// Represents a byte encoding which doesn't preserve the [`Ord`]
+/// implementation of the encoded values.
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum EncodingError {
+    /// `encode(a).cmp(&encode(b))` *MUST* be equal to `a.cmp(b)`.
+    #[error("encode(a).cmp(&encode(b)) MUST be equal to a.cmp(b)")]
+    BadOrder,
+    /// When two values are different, neither of the two encodings can be a
+    /// prefix of the other.
+    #[error(
+        "When two values are different, one of the two encodings CAN NOT be a prefix of the other"
+    )]
+    PrefixCollision,
+}
+
+/// Represents a broken invariant of [`Clone`] in relation to [`Eq`], [`Ord`],
+/// and [`Hash`](std::hash::Hash).
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum CloneError {
+    /// `a.clone()` *MUST* be equal to `a`.
+    #[error("a.clone() MUST be equal to a")]
+    NotEqual,
+    /// `a.clone().cmp(&a)` *MUST* be [`Ordering::Equal`](std::cmp::Ordering::Equal).
+    #[error("a.clone().cmp(&a) MUST be Ordering::Equal")]
+    CmpNotEqual,
+    /// `a.clone()` *MUST* write the same data to the hasher as `a`.
+    #[error("a.clone() MUST write the same data to the hasher as a")]
+    DifferentHashes,
+    /// [`Clone::clone_from`] *MUST* have the same result as [`Clone::clone`],
+    /// i.e. `b.clone_from(&a)` *MUST* make `b` equal to `a`.
+    #[error("b.clone_from(&a) MUST make b equal to a, just like a.clone()")]
+    BadCloneFrom,
 }
 
 /// Represents a broken invariant of [`Iterator`].
@@ -145,4 +413,260 @@ pub enum Error {
     Hash(#[from] HashError),
     #[error(transparent)]
     Iterator(#[from] IteratorError),
+    #[error(transparent)]
+    Monotonicity(#[from] MonotonicityError),
+    #[error(transparent)]
+    Encoding(#[from] EncodingError),
+    #[error(transparent)]
+    Borrow(#[from] BorrowError),
+    #[error(transparent)]
+    Equivalent(#[from] EquivalentError),
+    #[error(transparent)]
+    Clone(#[from] CloneError),
+}
+
+/// A broken invariant together with the evidence that exposed it.
+///
+/// Besides the underlying error (e.g. [`PartialEqError::BrokeTransitivity`]),
+/// a [`Violation`] records the intermediate results that each check
+/// observed (e.g. `a == b: true`, `b == c: true`, `a == c: false`) and, when
+/// the values implement [`Debug`](std::fmt::Debug), their
+/// [`Debug`](std::fmt::Debug) renderings.
+#[derive(Debug, Clone)]
+pub struct Violation<E = Error> {
+    error: E,
+    values: Vec<(String, String)>,
+    observations: Vec<(String, String)>,
+}
+
+impl<E> Violation<E> {
+    /// Creates a new [`Violation`] with no values nor observations attached.
+    pub fn new(error: E) -> Self {
+        Self {
+            error,
+            values: vec![],
+            observations: vec![],
+        }
+    }
+
+    /// Returns the broken invariant.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Discards all evidence and returns the broken invariant.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// Returns the [`Debug`](std::fmt::Debug) renderings of the values that
+    /// broke the invariant, by name (e.g. `("a", "1.0")`). This is empty
+    /// unless the values implement [`Debug`](std::fmt::Debug) and a
+    /// `*_verbose` checker was used.
+    pub fn values(&self) -> &[(String, String)] {
+        &self.values
+    }
+
+    /// Returns the sub-expressions evaluated by the broken invariant and their
+    /// results (e.g. `("a == c", "false")`), in evaluation order.
+    pub fn observations(&self) -> &[(String, String)] {
+        &self.observations
+    }
+
+    /// Converts the underlying error, keeping all evidence.
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> Violation<F> {
+        Violation {
+            error: f(self.error),
+            values: self.values,
+            observations: self.observations,
+        }
+    }
+
+    pub(crate) fn observe(mut self, expr: impl Into<String>, result: impl fmt::Debug) -> Self {
+        self.observations
+            .push((expr.into(), format!("{:?}", result)));
+        self
+    }
+
+    pub(crate) fn with_value<T>(mut self, name: impl Into<String>, value: &T) -> Self
+    where
+        T: fmt::Debug + ?Sized,
+    {
+        self.values.push((name.into(), format!("{:?}", value)));
+        self
+    }
+}
+
+impl<E> fmt::Display for Violation<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        for (name, value) in &self.values {
+            write!(f, "\n    {} = {}", name, value)?;
+        }
+        if !self.observations.is_empty() {
+            write!(f, "\n    where ")?;
+            for (i, (expr, result)) in self.observations.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: {}", expr, result)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E> std::error::Error for Violation<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+macro_rules! impl_from_violation {
+    ($($err:ty),*) => {
+        $(
+            impl From<Violation<$err>> for Violation<Error> {
+                fn from(violation: Violation<$err>) -> Self {
+                    violation.map(Error::from)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_violation!(
+    PartialEqError,
+    EqError,
+    PartialOrdError,
+    OrdError,
+    HashError,
+    IteratorError,
+    MonotonicityError,
+    EncodingError,
+    BorrowError,
+    EquivalentError,
+    CloneError
+);
+
+/// The outcome of running *all* invariants of a trait, as opposed to stopping
+/// at the first broken one.
+///
+/// Broken invariants can be downgraded to warnings with
+/// [`Report::downgrade`], which are then kept apart from the violations.
+#[derive(Debug, Clone)]
+pub struct Report<E = Error> {
+    violations: Vec<Violation<E>>,
+    warnings: Vec<Violation<E>>,
+}
+
+impl<E> Report<E> {
+    /// Creates an empty [`Report`].
+    pub fn new() -> Self {
+        Self {
+            violations: vec![],
+            warnings: vec![],
+        }
+    }
+
+    /// Returns [`true`] if no invariants were broken.
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns all broken invariants, in the order they were checked.
+    pub fn violations(&self) -> &[Violation<E>] {
+        &self.violations
+    }
+
+    /// Returns all broken invariants, in the order they were checked.
+    pub fn into_violations(self) -> Vec<Violation<E>> {
+        self.violations
+    }
+
+    /// Returns all broken invariants which were downgraded to warnings, in
+    /// the order they were checked.
+    pub fn warnings(&self) -> &[Violation<E>] {
+        &self.warnings
+    }
+
+    /// Moves all violations for which `is_warning` returns [`true`] to the
+    /// warnings, e.g. `report.downgrade(HashError::is_quality_issue)`.
+    pub fn downgrade(mut self, is_warning: impl Fn(&E) -> bool) -> Self {
+        let (warnings, violations) = self
+            .violations
+            .into_iter()
+            .partition(|violation| is_warning(violation.error()));
+        self.violations = violations;
+        self.warnings.extend(warnings);
+        self
+    }
+
+    /// Returns the first broken invariant, if any.
+    pub fn into_result(self) -> Result<(), Violation<E>> {
+        match self.violations.into_iter().next() {
+            Some(violation) => Err(violation),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn check<F>(&mut self, law: Result<(), Violation<F>>)
+    where
+        Violation<F>: Into<Violation<E>>,
+    {
+        if let Err(violation) = law {
+            self.violations.push(violation.into());
+        }
+    }
+}
+
+impl<E> Default for Report<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> IntoIterator for Report<E> {
+    type Item = Violation<E>;
+    type IntoIter = std::vec::IntoIter<Violation<E>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<E> fmt::Display for Report<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            write!(f, "No invariants were broken")?;
+        } else {
+            write!(f, "{} invariant(s) were broken:", self.violations.len())?;
+            write_numbered(f, &self.violations)?;
+        }
+
+        if !self.warnings.is_empty() {
+            write!(f, "\n{} warning(s):", self.warnings.len())?;
+            write_numbered(f, &self.warnings)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_numbered<E>(f: &mut fmt::Formatter<'_>, violations: &[Violation<E>]) -> fmt::Result
+where
+    E: fmt::Display,
+{
+    for (i, violation) in violations.iter().enumerate() {
+        let rendered = violation.to_string().replace('\n', "\n   ");
+        write!(f, "\n{}. {}", i + 1, rendered)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,365 @@
+//! A [`Hasher`] which records every write call instead of hashing, so that
+//! the data fed to it by a [`Hash`] implementation can be inspected and
+//! compared write by write.
+//!
+//! This is what the [`Hash`] checkers use under the hood: when one of them
+//! fails, the returned [`Violation`](crate::error::Violation) points to the
+//! first write at which the two values diverge.
+//!
+//! Since [`RecordingHasher::finish`] always returns `0`, this module also
+//! provides actual hashers which [`hash_under`](crate::hash_under) runs the
+//! [`Hash`] checkers with, see [`HasherKind`].
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// A single write call to a [`Hasher`], together with its argument.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HashWrite {
+    /// [`Hasher::write`].
+    Bytes(Vec<u8>),
+    /// [`Hasher::write_u8`].
+    U8(u8),
+    /// [`Hasher::write_u16`].
+    U16(u16),
+    /// [`Hasher::write_u32`].
+    U32(u32),
+    /// [`Hasher::write_u64`].
+    U64(u64),
+    /// [`Hasher::write_u128`].
+    U128(u128),
+    /// [`Hasher::write_usize`], which is also used for the length prefixes of
+    /// slices and collections.
+    Usize(usize),
+    /// [`Hasher::write_i8`].
+    I8(i8),
+    /// [`Hasher::write_i16`].
+    I16(i16),
+    /// [`Hasher::write_i32`].
+    I32(i32),
+    /// [`Hasher::write_i64`].
+    I64(i64),
+    /// [`Hasher::write_i128`].
+    I128(i128),
+    /// [`Hasher::write_isize`].
+    Isize(isize),
+}
+
+impl HashWrite {
+    /// Returns the name of the [`Hasher`] method, e.g. `"write_u32"`.
+    pub fn method(&self) -> &'static str {
+        match self {
+            Self::Bytes(_) => "write",
+            Self::U8(_) => "write_u8",
+            Self::U16(_) => "write_u16",
+            Self::U32(_) => "write_u32",
+            Self::U64(_) => "write_u64",
+            Self::U128(_) => "write_u128",
+            Self::Usize(_) => "write_usize",
+            Self::I8(_) => "write_i8",
+            Self::I16(_) => "write_i16",
+            Self::I32(_) => "write_i32",
+            Self::I64(_) => "write_i64",
+            Self::I128(_) => "write_i128",
+            Self::Isize(_) => "write_isize",
+        }
+    }
+
+    /// Returns the bytes which the default implementation of the method passes
+    /// on to [`Hasher::write`].
+    pub fn bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Bytes(bytes) => bytes.clone(),
+            Self::U8(i) => i.to_ne_bytes().to_vec(),
+            Self::U16(i) => i.to_ne_bytes().to_vec(),
+            Self::U32(i) => i.to_ne_bytes().to_vec(),
+            Self::U64(i) => i.to_ne_bytes().to_vec(),
+            Self::U128(i) => i.to_ne_bytes().to_vec(),
+            Self::Usize(i) => i.to_ne_bytes().to_vec(),
+            Self::I8(i) => i.to_ne_bytes().to_vec(),
+            Self::I16(i) => i.to_ne_bytes().to_vec(),
+            Self::I32(i) => i.to_ne_bytes().to_vec(),
+            Self::I64(i) => i.to_ne_bytes().to_vec(),
+            Self::I128(i) => i.to_ne_bytes().to_vec(),
+            Self::Isize(i) => i.to_ne_bytes().to_vec(),
+        }
+    }
+}
+
+impl fmt::Display for HashWrite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => write!(f, "write({:?})", bytes),
+            Self::U8(i) => write!(f, "write_u8({})", i),
+            Self::U16(i) => write!(f, "write_u16({})", i),
+            Self::U32(i) => write!(f, "write_u32({})", i),
+            Self::U64(i) => write!(f, "write_u64({})", i),
+            Self::U128(i) => write!(f, "write_u128({})", i),
+            Self::Usize(i) => write!(f, "write_usize({})", i),
+            Self::I8(i) => write!(f, "write_i8({})", i),
+            Self::I16(i) => write!(f, "write_i16({})", i),
+            Self::I32(i) => write!(f, "write_i32({})", i),
+            Self::I64(i) => write!(f, "write_i64({})", i),
+            Self::I128(i) => write!(f, "write_i128({})", i),
+            Self::Isize(i) => write!(f, "write_isize({})", i),
+        }
+    }
+}
+
+/// A [`Hasher`] which records every write call instead of hashing.
+///
+/// [`Hasher::finish`] always returns `0`, so this is only useful for
+/// inspecting [`Hash`] implementations.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingHasher {
+    writes: Vec<HashWrite>,
+}
+
+impl RecordingHasher {
+    /// Creates a [`RecordingHasher`] with no writes recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns all recorded writes, in order.
+    pub fn writes(&self) -> &[HashWrite] {
+        &self.writes
+    }
+
+    /// Returns all recorded writes, in order.
+    pub fn into_writes(self) -> Vec<HashWrite> {
+        self.writes
+    }
+
+    /// Returns all bytes written to the hasher, i.e. the concatenation of
+    /// [`HashWrite::bytes`] over all recorded writes.
+    pub fn output(&self) -> Vec<u8> {
+        output(&self.writes)
+    }
+}
+
+macro_rules! record {
+    ($($method:ident($ty:ty) => $variant:ident),*) => {
+        $(
+            fn $method(&mut self, i: $ty) {
+                self.writes.push(HashWrite::$variant(i));
+            }
+        )*
+    };
+}
+
+impl Hasher for RecordingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.writes.push(HashWrite::Bytes(bytes.to_vec()));
+    }
+
+    record!(
+        write_u8(u8) => U8,
+        write_u16(u16) => U16,
+        write_u32(u32) => U32,
+        write_u64(u64) => U64,
+        write_u128(u128) => U128,
+        write_usize(usize) => Usize,
+        write_i8(i8) => I8,
+        write_i16(i16) => I16,
+        write_i32(i32) => I32,
+        write_i64(i64) => I64,
+        write_i128(i128) => I128,
+        write_isize(isize) => Isize
+    );
+}
+
+/// A 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+/// hasher, which only implements [`Hasher::write`].
+#[derive(Debug, Clone)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// A hasher which overrides every `write_*` method, so that the same bytes
+/// hash differently depending on the method (and, for [`Hasher::write`], the
+/// chunks) they're written with.
+///
+/// [`Hash`] implementations must write the same data *in the same way* for
+/// equal values, which the default implementations of the `write_*` methods
+/// can't tell apart.
+#[derive(Debug, Clone, Default)]
+pub struct TypedHasher(FnvHasher);
+
+macro_rules! tagged {
+    ($($method:ident($ty:ty) => $tag:literal),*) => {
+        $(
+            fn $method(&mut self, i: $ty) {
+                self.0.write(&[$tag]);
+                self.0.write(&i.to_ne_bytes());
+            }
+        )*
+    };
+}
+
+impl Hasher for TypedHasher {
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(&[0]);
+        self.0.write(&bytes.len().to_ne_bytes());
+        self.0.write(bytes);
+    }
+
+    tagged!(
+        write_u8(u8) => 1,
+        write_u16(u16) => 2,
+        write_u32(u32) => 3,
+        write_u64(u64) => 4,
+        write_u128(u128) => 5,
+        write_usize(usize) => 6,
+        write_i8(i8) => 7,
+        write_i16(i16) => 8,
+        write_i32(i32) => 9,
+        write_i64(i64) => 10,
+        write_i128(i128) => 11,
+        write_isize(isize) => 12
+    );
+}
+
+/// The hashers which [`hash_under`](crate::hash_under) can run the [`Hash`]
+/// checkers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HasherKind {
+    /// [`DefaultHasher`], i.e. the hasher used by [`HashMap`](std::collections::HashMap).
+    Default,
+    /// [`FnvHasher`].
+    Fnv,
+    /// [`TypedHasher`].
+    Typed,
+}
+
+impl HasherKind {
+    /// All hashers.
+    pub const ALL: [HasherKind; 3] = [Self::Default, Self::Fnv, Self::Typed];
+
+    /// Hashes `value` with a new hasher of this kind.
+    pub fn hash<K>(self, value: &K) -> u64
+    where
+        K: Hash + ?Sized,
+    {
+        fn finish<H: Hasher, K: Hash + ?Sized>(mut hasher: H, value: &K) -> u64 {
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        match self {
+            Self::Default => finish(DefaultHasher::new(), value),
+            Self::Fnv => finish(FnvHasher::default(), value),
+            Self::Typed => finish(TypedHasher::default(), value),
+        }
+    }
+}
+
+impl fmt::Display for HasherKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Default => "DefaultHasher",
+            Self::Fnv => "FnvHasher",
+            Self::Typed => "TypedHasher",
+        })
+    }
+}
+
+/// Returns all writes made by the [`Hash`] implementation of `value`.
+pub fn trace<K>(value: &K) -> Vec<HashWrite>
+where
+    K: Hash + ?Sized,
+{
+    let mut hasher = RecordingHasher::new();
+    value.hash(&mut hasher);
+    hasher.into_writes()
+}
+
+/// The first write at which two traces diverge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashDiff {
+    index: usize,
+    a: Option<HashWrite>,
+    b: Option<HashWrite>,
+}
+
+impl HashDiff {
+    /// Returns the index of the first divergent write.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the write of the first trace, or [`None`] if it ended first.
+    pub fn a(&self) -> Option<&HashWrite> {
+        self.a.as_ref()
+    }
+
+    /// Returns the write of the second trace, or [`None`] if it ended first.
+    pub fn b(&self) -> Option<&HashWrite> {
+        self.b.as_ref()
+    }
+}
+
+impl fmt::Display for HashDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let render = |write: &Option<HashWrite>| match write {
+            Some(write) => write.to_string(),
+            None => "nothing".to_string(),
+        };
+        write!(
+            f,
+            "writes[{}]: {} vs {}",
+            self.index,
+            render(&self.a),
+            render(&self.b)
+        )
+    }
+}
+
+/// Returns the first write at which `a` and `b` diverge, or [`None`] if
+/// they're identical.
+pub fn diff(a: &[HashWrite], b: &[HashWrite]) -> Option<HashDiff> {
+    let index = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    if index == a.len() && index == b.len() {
+        return None;
+    }
+
+    Some(HashDiff {
+        index,
+        a: a.get(index).cloned(),
+        b: b.get(index).cloned(),
+    })
+}
+
+pub(crate) fn output(writes: &[HashWrite]) -> Vec<u8> {
+    writes.iter().flat_map(HashWrite::bytes).collect()
+}
@@ -2,13 +2,9 @@
 //! implement [`PartialEq`] and [`PartialOrd`] with a non-`Self` type parameter
 //! and you can't satisfy the type bounds of the main helper functions.
 
-use std::{
-    cmp::{max_by, min_by, Ordering},
-    hash::{Hash, Hasher},
-    iter::FusedIterator,
-};
+use std::{hash::Hash, iter::FusedIterator};
 
-use crate::error::*;
+use crate::{error::*, laws};
 
 /// Checks that [`PartialEq::eq`] and [`PartialEq::ne`] are strict inverses.
 ///
@@ -18,11 +14,7 @@ pub fn partial_eq_methods_consistency<A, B>(a: &A, b: &B) -> Result<(), PartialE
 where
     A: PartialEq<B>,
 {
-    if (a == b) != !(a != b) {
-        return Err(PartialEqError::BadNe);
-    }
-
-    Ok(())
+    laws::partial_eq_methods_consistency(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that [`PartialEq`] is a
@@ -32,11 +24,7 @@ where
     A: PartialEq<B>,
     B: PartialEq<A>,
 {
-    if (a == b) != (b == a) {
-        return Err(PartialEqError::BrokeSymmetry);
-    }
-
-    Ok(())
+    laws::partial_eq_symmetry(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that [`PartialEq`] is a [transitive
@@ -46,11 +34,7 @@ where
     A: PartialEq<B> + PartialEq<C>,
     B: PartialEq<C>,
 {
-    if a == b && b == c && a != c {
-        return Err(PartialEqError::BrokeTransitivity);
-    }
-
-    Ok(())
+    laws::partial_eq_transitivity(a, b, c).map_err(Violation::into_error)
 }
 
 /// Checks that [`PartialEq`] is a [reflexive
@@ -62,11 +46,7 @@ pub fn eq_reflexivity<A>(a: &A) -> Result<(), PartialEqError>
 where
     A: PartialEq<A>,
 {
-    if a != a {
-        return Err(PartialEqError::BrokeTransitivity);
-    }
-
-    Ok(())
+    laws::eq_reflexivity(a).map_err(|_| PartialEqError::BrokeReflexivity)
 }
 
 /// Checks that [`PartialOrd`] methods are implemented consistently with
@@ -78,89 +58,184 @@ pub fn partial_ord_methods_consistency<A, B>(a: &A, b: &B) -> Result<(), Partial
 where
     A: PartialOrd<B>,
 {
-    if (a == b) != (a.partial_cmp(b) == Some(Ordering::Equal)) {
-        return Err(PartialOrdError::BadPartialCmp);
-    }
-    if (a < b) != (a.partial_cmp(b) == Some(Ordering::Less)) {
-        return Err(PartialOrdError::BadLt);
-    }
-    if (a > b) != (a.partial_cmp(b) == Some(Ordering::Greater)) {
-        return Err(PartialOrdError::BadGt);
-    }
-    if (a <= b) != ((a < b) || (a == b)) {
-        return Err(PartialOrdError::BadLe);
-    }
-    if (a >= b) != ((a > b) || (a == b)) {
-        return Err(PartialOrdError::BadGe);
-    }
-
-    Ok(())
+    laws::partial_ord_methods_consistency(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that [`PartialOrd`] respects
 /// [duality](https://en.wikipedia.org/wiki/Duality_(order_theory)) (i.e. `a
 /// > b` iff `b < a`).
+///
+/// More generally, `a.partial_cmp(b)` must be equal to
+/// `b.partial_cmp(a).map(Ordering::reverse)`, which implies that `a` and `b`
+/// are either comparable in both directions or in neither.
 pub fn partial_ord_duality<A, B>(a: &A, b: &B) -> Result<(), PartialOrdError>
 where
     A: PartialOrd<B>,
     B: PartialOrd<A>,
 {
-    if ((a < b) != (b > a)) && ((a > b) != (b < a)) {
-        return Err(PartialOrdError::BrokeDuality);
-    }
+    laws::partial_ord_duality(a, b).map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Checks that, if [`PartialOrd::partial_cmp`] returns [`None`], all of `<`,
+/// `<=`, `>`, `>=`, and `==` are false.
+pub fn partial_ord_incomparability<A, B>(a: &A, b: &B) -> Result<(), PartialOrdError>
+where
+    A: PartialOrd<B>,
+{
+    laws::partial_ord_incomparability(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that [`PartialOrd`] is a [transitive
 /// relation](https://en.wikipedia.org/wiki/Transitive_relation).
+///
+/// Besides chains of `<` and of `>`, this also checks chains which mix `==`,
+/// `<`, and `<=` (e.g. `a < b && b == c` implies `a < c`), and the same for
+/// `==`, `>`, and `>=`.
 pub fn partial_ord_transitivity<A, B, C>(a: &A, b: &B, c: &C) -> Result<(), PartialOrdError>
 where
     A: PartialOrd<B> + PartialOrd<C>,
     B: PartialOrd<C>,
 {
-    if a < b && b < c && !(a < c) {
-        return Err(PartialOrdError::BrokeTransitivity);
-    }
-    if a > b && b > c && !(a > c) {
-        return Err(PartialOrdError::BrokeTransitivity);
-    }
+    laws::partial_ord_transitivity(a, b, c).map_err(Violation::into_error)
+}
+
+/// Checks that `<` and `>` are [irreflexive
+/// relations](https://en.wikipedia.org/wiki/Reflexive_relation#Irreflexive_relation),
+/// i.e. that no value is smaller or greater than itself.
+pub fn partial_ord_irreflexivity<A>(a: &A) -> Result<(), PartialOrdError>
+where
+    A: PartialOrd<A>,
+{
+    laws::partial_ord_irreflexivity(a).map_err(Violation::into_error)
+}
+
+/// Checks that `<` and `>` are [asymmetric
+/// relations](https://en.wikipedia.org/wiki/Asymmetric_relation) (i.e. `a <
+/// b` implies `!(b < a)`).
+pub fn partial_ord_asymmetry<A, B>(a: &A, b: &B) -> Result<(), PartialOrdError>
+where
+    A: PartialOrd<B>,
+    B: PartialOrd<A>,
+{
+    laws::partial_ord_asymmetry(a, b).map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Checks that `<=` is an [antisymmetric
+/// relation](https://en.wikipedia.org/wiki/Antisymmetric_relation) (i.e. `a
+/// <= b && b <= a` implies `a == b`).
+pub fn partial_ord_antisymmetry<A, B>(a: &A, b: &B) -> Result<(), PartialOrdError>
+where
+    A: PartialOrd<B>,
+    B: PartialOrd<A>,
+{
+    laws::partial_ord_antisymmetry(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that incomparability (i.e. neither `a < b` nor `b < a`) is a
+/// [transitive relation](https://en.wikipedia.org/wiki/Transitive_relation).
+///
+/// Note that [`PartialOrd`] alone does **not** require this, but [strict weak
+/// orderings](https://en.wikipedia.org/wiki/Weak_ordering#Strict_weak_orderings)
+/// do. See [`strict_weak_order`](crate::strict_weak_order).
+pub fn incomparability_transitivity<T>(a: &T, b: &T, c: &T) -> Result<(), PartialOrdError>
+where
+    T: PartialOrd,
+{
+    laws::incomparability_transitivity(a, b, c).map_err(Violation::into_error)
 }
 
 /// Checks that [`Ord`] methods are implemented consistently with each other.
 ///
 /// This is guaranteed by default method implementations but may be broken
 /// by non-default method implementations.
-pub fn ord_methods_consistency<T>(a: &T, b: &T, c: &T) -> Result<(), OrdError>
+///
+/// Only [`Ord::cmp`] is checked against [`PartialOrd::partial_cmp`]: calling
+/// [`Ord::max`], [`Ord::min`], and [`Ord::clamp`] on `&T` would use the
+/// implementation of [`Ord`] for `&T` rather than your overrides, so this
+/// never returns [`OrdError::BadMax`], [`OrdError::BadMin`], or
+/// [`OrdError::BadClamp`], and `c` is unused. Use [`ord_methods_exactness`]
+/// to check those methods.
+pub fn ord_methods_consistency<T>(a: &T, b: &T, _c: &T) -> Result<(), OrdError>
+where
+    T: Ord,
+{
+    laws::ord_methods_consistency(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that [`Ord::cmp`] is antisymmetric, i.e. that `a.cmp(b)` is equal to
+/// `b.cmp(a).reverse()`.
+pub fn ord_cmp_antisymmetry<T>(a: &T, b: &T) -> Result<(), OrdError>
 where
     T: Ord,
 {
-    if a.partial_cmp(b) != Some(a.cmp(b)) {
-        return Err(OrdError::BadCmp);
-    }
-    if a.max(b) != max_by(a, b, |x, y| x.cmp(y)) {
-        return Err(OrdError::BadMax);
-    }
-    if a.min(b) != min_by(a, b, |x, y| x.cmp(y)) {
-        return Err(OrdError::BadMin);
-    }
+    laws::ord_cmp_antisymmetry(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that [`Ord::max`], [`Ord::min`], and [`Ord::clamp`] return exactly
+/// the same values as their default implementations, including which argument
+/// is returned when the two compare equal, and that [`Ord::clamp`] panics if
+/// `min > max`.
+///
+/// Unlike [`ord_methods_consistency`], this calls the methods on clones of the
+/// values, which exercises your overrides rather than the implementation of
+/// [`Ord`] for `&T`. Values which compare equal are told apart by their
+/// [`Debug`](std::fmt::Debug) renderings.
+///
+/// Catching the [`Ord::clamp`] panic requires unwinding, see
+/// [`ord_exact`](crate::ord_exact).
+pub fn ord_methods_exactness<T>(a: &T, b: &T, c: &T) -> Result<(), OrdError>
+where
+    T: Ord + Clone + std::fmt::Debug,
+{
+    laws::ord_methods_exactness(a, b, c).map_err(Violation::into_error)
+}
+
+/// Checks that [`PartialEq::eq`] is pure, i.e. that repeated calls of `a ==
+/// b` return the same result, even when interleaved with other comparisons of
+/// `a` and `b`.
+///
+/// Types with interior mutability (e.g. lazy caches or counters) can break
+/// this, which in turn breaks collections in subtle ways.
+pub fn partial_eq_purity<T>(a: &T, b: &T) -> Result<(), PartialEqError>
+where
+    T: PartialEq,
+{
+    laws::partial_eq_purity(a, b).map_err(Violation::into_error)
+}
+
+/// Like [`partial_eq_purity`], but for [`PartialOrd::partial_cmp`].
+pub fn partial_ord_purity<T>(a: &T, b: &T) -> Result<(), PartialOrdError>
+where
+    T: PartialOrd,
+{
+    laws::partial_ord_purity(a, b).map_err(Violation::into_error)
+}
 
-    // clamp
-    let min = b.min(c);
-    let max = b.max(c);
-    let clamped = a.clamp(min, max);
-    if clamped < min || clamped > max {
-        return Err(OrdError::BadClamp);
-    }
+/// Like [`partial_eq_purity`], but for [`Ord::cmp`].
+pub fn ord_purity<T>(a: &T, b: &T) -> Result<(), OrdError>
+where
+    T: Ord,
+{
+    laws::ord_purity(a, b).map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Like [`partial_eq_purity`], but checks that hashing `a` repeatedly
+/// always writes the same data to the hasher, even when interleaved with
+/// comparisons of `a` and `b` and hashing `b`.
+pub fn hash_purity<K>(a: &K, b: &K) -> Result<(), HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    laws::hash_purity(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that the output of [`Hash`] is the same for equal values, and
 /// different for different values.
 ///
+/// The former is a correctness bug
+/// ([`HashError::EqualButDifferentHashes`]), while the latter is only a
+/// quality issue ([`HashError::UnequalButSameHashes`]).
+///
 /// See what the `std`
 /// [docs](https://doc.rust-lang.org/std/hash/trait.Hash.html#hash-and-eq) have
 /// to say about this invariant.
@@ -168,18 +243,11 @@ pub fn hash_consistency_with_eq<K>(a: &K, b: &K) -> Result<(), HashError>
 where
     K: Hash + Eq + ?Sized,
 {
-    let hasher_output_equality = hasher_output(a) == hasher_output(b);
-    let equality = a == b;
-
-    if hasher_output_equality != equality {
-        return Err(HashError::EqualButDifferentHashes);
-    }
-
-    Ok(())
+    laws::hash_consistency_with_eq(a, b).map_err(Violation::into_error)
 }
 
 /// Checks that neither of the outputs of [`Hash`] of two different values is a
-/// prefix of the other.
+/// proper prefix of the other.
 ///
 /// See what the `std`
 /// [docs](https://doc.rust-lang.org/std/hash/trait.Hash.html#prefix-collisions) have
@@ -188,18 +256,93 @@ pub fn hash_prefix_collision<K>(a: &K, b: &K) -> Result<(), HashError>
 where
     K: Hash + Eq + ?Sized,
 {
-    if a != b {
-        let hasher_output_a = hasher_output(a);
-        let hasher_output_b = hasher_output(b);
+    laws::hash_prefix_collision(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that two different pairs of values, `(a, b)` and `(c, d)`, don't
+/// write the same data to the hasher when hashed one value after the other.
+///
+/// This is the failure mode that prefix-freedom guards against, e.g. `("ab",
+/// "c")` and `("a", "bc")` colliding in a composite hash. See what the `std`
+/// [docs](https://doc.rust-lang.org/std/hash/trait.Hash.html#prefix-collisions)
+/// have to say about this invariant.
+pub fn hash_pair_concatenation<K>(ab: (&K, &K), cd: (&K, &K)) -> Result<(), HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    laws::hash_pair_concatenation(ab, cd).map_err(Violation::into_error)
+}
+
+/// Like [`hash_pair_concatenation`], but for two slices of values of any
+/// length. Unlike hashing the slices themselves, no length prefix is written.
+pub fn hash_slice_concatenation<K>(a: &[K], b: &[K]) -> Result<(), HashError>
+where
+    K: Hash + Eq,
+{
+    laws::hash_slice_concatenation(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that `a.clone()` is equal to `a`.
+pub fn clone_consistency_with_eq<T>(a: &T) -> Result<(), CloneError>
+where
+    T: Clone + PartialEq,
+{
+    laws::clone_consistency_with_eq(a).map_err(Violation::into_error)
+}
+
+/// Checks that `a.clone().cmp(&a)` is [`Ordering::Equal`](std::cmp::Ordering::Equal).
+pub fn clone_consistency_with_ord<T>(a: &T) -> Result<(), CloneError>
+where
+    T: Clone + Ord,
+{
+    laws::clone_consistency_with_ord(a).map_err(Violation::into_error)
+}
+
+/// Checks that both `a.clone()` and `b.clone_from(&a)` write the same data to
+/// the hasher as `a`.
+pub fn clone_consistency_with_hash<T>(a: &T, b: &T) -> Result<(), CloneError>
+where
+    T: Clone + Hash,
+{
+    laws::clone_consistency_with_hash(a, b).map_err(Violation::into_error)
+}
 
-        if hasher_output_a.starts_with(&hasher_output_b)
-            || hasher_output_b.starts_with(&hasher_output_a)
-        {
-            return Err(HashError::PrefixCollision);
-        }
-    }
+/// Checks that [`Clone::clone_from`] has the same result as [`Clone::clone`],
+/// i.e. that `b.clone_from(&a)` makes `b` equal to `a`.
+pub fn clone_from_consistency<T>(a: &T, b: &T) -> Result<(), CloneError>
+where
+    T: Clone + PartialEq,
+{
+    laws::clone_from_consistency(a, b).map_err(Violation::into_error)
+}
+
+/// Checks that [`Hash::hash_slice`] is consistent with slice equality, i.e.
+/// that every permutation of `[a, b, c]` has the same [`Hash::hash_slice`]
+/// output as a clone of itself and as any equal permutation.
+///
+/// Unequal permutations with identical outputs are only a quality issue
+/// ([`HashError::HashSliceUnequalButSameHashes`]), so they're reported after
+/// equal ones with different outputs.
+///
+/// This matters for types which override [`Hash::hash_slice`], as it's what
+/// `[T]` and [`Vec<T>`] use to hash their elements.
+pub fn hash_slice_consistency_with_eq<T>(a: &T, b: &T, c: &T) -> Result<(), HashError>
+where
+    T: Hash + Eq + Clone,
+{
+    laws::hash_slice_consistency_with_eq(a, b, c).map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Checks that [`Hash::hash_slice`] writes the same data to the hasher as
+/// hashing each element of the permutations of `[a, b, c]` in turn.
+///
+/// Unlike [`hash_slice_consistency_with_eq`], this is only a quality issue,
+/// as `std` leaves the output of [`Hash::hash_slice`] unspecified.
+pub fn hash_slice_elementwise<T>(a: &T, b: &T, c: &T) -> Result<(), HashError>
+where
+    T: Hash + Clone,
+{
+    laws::hash_slice_elementwise(a, b, c).map_err(Violation::into_error)
 }
 
 /// Checks that [`Iterator::size_hint`] provides correct lower and upper bounds
@@ -208,18 +351,7 @@ pub fn iterator_size_hint<I>(iter: I) -> Result<(), IteratorError>
 where
     I: Iterator,
 {
-    let size_hint = iter.size_hint();
-    let count = iter.count();
-
-    if size_hint.0 > count {
-        return Err(IteratorError::BadSizeHint);
-    } else if let Some(upper_bound) = size_hint.1 {
-        if upper_bound < count {
-            return Err(IteratorError::BadSizeHint);
-        }
-    }
-
-    Ok(())
+    laws::iterator_size_hint(iter).map_err(Violation::into_error)
 }
 
 /// Checks that [`Iterator::count`] returns the same value as the length of the
@@ -228,14 +360,7 @@ pub fn iterator_count<I>(iter: I) -> Result<(), IteratorError>
 where
     I: Iterator + Clone,
 {
-    let count = iter.clone().count();
-    let collected = iter.collect::<Vec<_>>();
-
-    if count != collected.len() {
-        return Err(IteratorError::BadCount);
-    }
-
-    Ok(())
+    laws::iterator_count(iter).map_err(Violation::into_error)
 }
 
 /// Checks that [`Iterator::last`] returns the same value as the last element of
@@ -245,96 +370,25 @@ where
     I: Iterator + Clone,
     I::Item: PartialEq,
 {
-    let last = iter.clone().last();
-    let collected = iter.collect::<Vec<_>>();
-
-    if last.as_ref() != collected.last() {
-        return Err(IteratorError::BadLast);
-    }
-
-    Ok(())
+    laws::iterator_last(iter).map_err(Violation::into_error)
 }
 
 /// Checks that alternating random calls to [`Iterator::next`] and
 /// [`DoubleEndedIterator::next_back`] results in the same sequence as the
 /// [`Vec`] obtained from [`Iterator::collect`].
-pub fn double_ended_iterator_next_back<I>(mut iter: I) -> Result<(), IteratorError>
+pub fn double_ended_iterator_next_back<I>(iter: I) -> Result<(), IteratorError>
 where
     I: DoubleEndedIterator + Clone,
     I::Item: PartialEq,
 {
-    let collected = iter.clone().collect::<Vec<_>>();
-
-    let mut from_start = vec![];
-    let mut from_end = vec![];
-    loop {
-        if rand::random() {
-            if let Some(item) = iter.next() {
-                from_start.push(item);
-            } else {
-                break;
-            }
-        } else {
-            if let Some(item) = iter.next_back() {
-                from_end.push(item);
-            } else {
-                break;
-            }
-        }
-    }
-
-    let assembled = from_start
-        .into_iter()
-        .chain(from_end.into_iter().rev())
-        .collect::<Vec<_>>();
-
-    if assembled != collected {
-        return Err(IteratorError::BadNextBack);
-    }
-
-    Ok(())
+    laws::double_ended_iterator_next_back(iter).map_err(Violation::into_error)
 }
 
 /// Checks that [`FusedIterator`] returns [`None`] for a large number of times after
 /// returning [`None`] for the first time.
-pub fn fused_iterator_none_forever<I>(mut iter: I) -> Result<(), IteratorError>
+pub fn fused_iterator_none_forever<I>(iter: I) -> Result<(), IteratorError>
 where
     I: FusedIterator + Clone,
 {
-    let mut count = 0;
-    while iter.next().is_some() {
-        count += 1;
-    }
-
-    // How many times does it make sense to keep going to have decent confidence
-    // it will return `None` forever? Hard to say. I'm going with .count() + 1
-    // in case the iterator "goes back" or something.
-    for _ in 0..count + 1 {
-        if iter.next().is_some() {
-            return Err(IteratorError::FusedIteratorReturnedSomeAfterExhaustion);
-        }
-    }
-
-    Ok(())
-}
-
-fn hasher_output<K>(item: &K) -> Vec<u8>
-where
-    K: Hash + ?Sized,
-{
-    struct NoHasher(Vec<u8>);
-
-    impl Hasher for NoHasher {
-        fn finish(&self) -> u64 {
-            0
-        }
-
-        fn write(&mut self, bytes: &[u8]) {
-            self.0.extend_from_slice(bytes);
-        }
-    }
-
-    let mut hasher = NoHasher(vec![]);
-    item.hash(&mut hasher);
-    hasher.0
+    laws::fused_iterator_none_forever(iter).map_err(Violation::into_error)
 }
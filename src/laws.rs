@@ -0,0 +1,1340 @@
+//! Implementations of the invariants exposed by [`crate::invariants`].
+//!
+//! Unlike their public counterparts, these return a [`Violation`] which
+//! records the sub-expressions that each check evaluated, so that top-level
+//! helpers can explain *why* an invariant was broken.
+
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+
+use crate::{
+    error::*,
+    hasher::{self, HashWrite, HasherKind, RecordingHasher},
+};
+
+pub type Law<E> = Result<(), Violation<E>>;
+
+pub fn partial_eq_methods_consistency<A, B>(a: &A, b: &B) -> Law<PartialEqError>
+where
+    A: PartialEq<B>,
+{
+    let eq = a == b;
+    let ne = a != b;
+    if eq == ne {
+        return Err(Violation::new(PartialEqError::BadNe)
+            .observe("a == b", eq)
+            .observe("a != b", ne));
+    }
+
+    Ok(())
+}
+
+pub fn partial_eq_symmetry<A, B>(a: &A, b: &B) -> Law<PartialEqError>
+where
+    A: PartialEq<B>,
+    B: PartialEq<A>,
+{
+    let ab = a == b;
+    let ba = b == a;
+    if ab != ba {
+        return Err(Violation::new(PartialEqError::BrokeSymmetry)
+            .observe("a == b", ab)
+            .observe("b == a", ba));
+    }
+
+    Ok(())
+}
+
+pub fn partial_eq_transitivity<A, B, C>(a: &A, b: &B, c: &C) -> Law<PartialEqError>
+where
+    A: PartialEq<B> + PartialEq<C>,
+    B: PartialEq<C>,
+{
+    let ab = a == b;
+    let bc = b == c;
+    let ac = a == c;
+    if ab && bc && !ac {
+        return Err(Violation::new(PartialEqError::BrokeTransitivity)
+            .observe("a == b", ab)
+            .observe("b == c", bc)
+            .observe("a == c", ac));
+    }
+
+    Ok(())
+}
+
+pub fn eq_reflexivity<A>(a: &A) -> Law<EqError>
+where
+    A: PartialEq<A>,
+{
+    let aa = a == a;
+    if !aa {
+        return Err(Violation::new(EqError::BrokeReflexivity).observe("a == a", aa));
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_methods_consistency<A, B>(a: &A, b: &B) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B>,
+{
+    let partial_cmp = a.partial_cmp(b);
+    let violation = |error: PartialOrdError, expr: &str, result: bool| {
+        Err(Violation::new(error)
+            .observe("a.partial_cmp(b)", partial_cmp)
+            .observe(expr, result))
+    };
+
+    let eq = a == b;
+    if eq != (partial_cmp == Some(Ordering::Equal)) {
+        return violation(PartialOrdError::BadPartialCmp, "a == b", eq);
+    }
+    let lt = a < b;
+    if lt != (partial_cmp == Some(Ordering::Less)) {
+        return violation(PartialOrdError::BadLt, "a < b", lt);
+    }
+    let gt = a > b;
+    if gt != (partial_cmp == Some(Ordering::Greater)) {
+        return violation(PartialOrdError::BadGt, "a > b", gt);
+    }
+    let le = a <= b;
+    if le != (lt || eq) {
+        return violation(PartialOrdError::BadLe, "a <= b", le);
+    }
+    let ge = a >= b;
+    if ge != (gt || eq) {
+        return violation(PartialOrdError::BadGe, "a >= b", ge);
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_duality<A, B>(a: &A, b: &B) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B>,
+    B: PartialOrd<A>,
+{
+    let (a_lt_b, b_gt_a) = (a < b, b > a);
+    if a_lt_b != b_gt_a {
+        return Err(Violation::new(PartialOrdError::BrokeDuality)
+            .observe("a < b", a_lt_b)
+            .observe("b > a", b_gt_a));
+    }
+    let (a_gt_b, b_lt_a) = (a > b, b < a);
+    if a_gt_b != b_lt_a {
+        return Err(Violation::new(PartialOrdError::BrokeDuality)
+            .observe("a > b", a_gt_b)
+            .observe("b < a", b_lt_a));
+    }
+
+    let (ab, ba) = (a.partial_cmp(b), b.partial_cmp(a));
+    let violation = |error| {
+        Err(Violation::new(error)
+            .observe("a.partial_cmp(b)", ab)
+            .observe("b.partial_cmp(a)", ba))
+    };
+    if ab.is_none() != ba.is_none() {
+        return violation(PartialOrdError::BrokeIncomparabilitySymmetry);
+    }
+    if ab != ba.map(Ordering::reverse) {
+        return violation(PartialOrdError::BrokePartialCmpDuality);
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_incomparability<A, B>(a: &A, b: &B) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B>,
+{
+    let partial_cmp = a.partial_cmp(b);
+    if partial_cmp.is_none() {
+        let relations = Relations::of(a, b);
+        if relations.lt || relations.le || relations.eq || relations.ge || relations.gt {
+            return Err(Violation::new(PartialOrdError::BadIncomparable)
+                .observe("a.partial_cmp(b)", partial_cmp)
+                .observe("a < b", relations.lt)
+                .observe("a <= b", relations.le)
+                .observe("a == b", relations.eq)
+                .observe("a >= b", relations.ge)
+                .observe("a > b", relations.gt));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_transitivity<A, B, C>(a: &A, b: &B, c: &C) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B> + PartialOrd<C>,
+    B: PartialOrd<C>,
+{
+    let (ab, bc, ac) = (a < b, b < c, a < c);
+    if ab && bc && !ac {
+        return Err(Violation::new(PartialOrdError::BrokeTransitivity)
+            .observe("a < b", ab)
+            .observe("b < c", bc)
+            .observe("a < c", ac));
+    }
+    let (ab, bc, ac) = (a > b, b > c, a > c);
+    if ab && bc && !ac {
+        return Err(Violation::new(PartialOrdError::BrokeTransitivity)
+            .observe("a > b", ab)
+            .observe("b > c", bc)
+            .observe("a > c", ac));
+    }
+
+    // `a R1 b && b R2 c` implies `a R c`.
+    use Relation::*;
+    const MIXED_CHAINS: [(Relation, Relation, Relation); 14] = [
+        (Lt, Eq, Lt),
+        (Eq, Lt, Lt),
+        (Lt, Le, Lt),
+        (Le, Lt, Lt),
+        (Le, Le, Le),
+        (Le, Eq, Le),
+        (Eq, Le, Le),
+        (Gt, Eq, Gt),
+        (Eq, Gt, Gt),
+        (Gt, Ge, Gt),
+        (Ge, Gt, Gt),
+        (Ge, Ge, Ge),
+        (Ge, Eq, Ge),
+        (Eq, Ge, Ge),
+    ];
+
+    let (ab, bc, ac) = (
+        Relations::of(a, b),
+        Relations::of(b, c),
+        Relations::of(a, c),
+    );
+    for (r1, r2, r) in MIXED_CHAINS {
+        if ab.get(r1) && bc.get(r2) && !ac.get(r) {
+            return Err(Violation::new(PartialOrdError::BrokeMixedTransitivity)
+                .observe(format!("a {} b", r1.operator()), true)
+                .observe(format!("b {} c", r2.operator()), true)
+                .observe(format!("a {} c", r.operator()), false));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_irreflexivity<A>(a: &A) -> Law<PartialOrdError>
+where
+    A: PartialOrd<A>,
+{
+    let lt = a < a;
+    let gt = a > a;
+    if lt || gt {
+        return Err(Violation::new(PartialOrdError::BrokeIrreflexivity)
+            .observe("a < a", lt)
+            .observe("a > a", gt));
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_asymmetry<A, B>(a: &A, b: &B) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B>,
+    B: PartialOrd<A>,
+{
+    let (ab, ba) = (a < b, b < a);
+    if ab && ba {
+        return Err(Violation::new(PartialOrdError::BrokeAsymmetry)
+            .observe("a < b", ab)
+            .observe("b < a", ba));
+    }
+    let (ab, ba) = (a > b, b > a);
+    if ab && ba {
+        return Err(Violation::new(PartialOrdError::BrokeAsymmetry)
+            .observe("a > b", ab)
+            .observe("b > a", ba));
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_antisymmetry<A, B>(a: &A, b: &B) -> Law<PartialOrdError>
+where
+    A: PartialOrd<B>,
+    B: PartialOrd<A>,
+{
+    let (ab, ba) = (a <= b, b <= a);
+    if ab && ba {
+        let eq = a == b;
+        if !eq {
+            return Err(Violation::new(PartialOrdError::BrokeAntisymmetry)
+                .observe("a <= b", ab)
+                .observe("b <= a", ba)
+                .observe("a == b", eq));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn incomparability_transitivity<T>(a: &T, b: &T, c: &T) -> Law<PartialOrdError>
+where
+    T: PartialOrd,
+{
+    let comparable = |x: &T, y: &T| x < y || y < x;
+    let (ab, bc, ac) = (comparable(a, b), comparable(b, c), comparable(a, c));
+    if !ab && !bc && ac {
+        return Err(
+            Violation::new(PartialOrdError::BrokeIncomparabilityTransitivity)
+                .observe("a < b || b < a", ab)
+                .observe("b < c || c < b", bc)
+                .observe("a < c || c < a", ac),
+        );
+    }
+
+    Ok(())
+}
+
+pub fn ord_methods_consistency<T>(a: &T, b: &T) -> Law<OrdError>
+where
+    T: Ord,
+{
+    let partial_cmp = a.partial_cmp(b);
+    let cmp = a.cmp(b);
+    if partial_cmp != Some(cmp) {
+        return Err(Violation::new(OrdError::BadCmp)
+            .observe("a.partial_cmp(b)", partial_cmp)
+            .observe("a.cmp(b)", cmp));
+    }
+
+    Ok(())
+}
+
+pub fn ord_cmp_antisymmetry<T>(a: &T, b: &T) -> Law<OrdError>
+where
+    T: Ord,
+{
+    let (ab, ba) = (a.cmp(b), b.cmp(a));
+    if ab != ba.reverse() {
+        return Err(Violation::new(OrdError::BrokeCmpAntisymmetry)
+            .observe("a.cmp(b)", ab)
+            .observe("b.cmp(a)", ba));
+    }
+
+    Ok(())
+}
+
+pub fn ord_methods_exactness<T>(a: &T, b: &T, c: &T) -> Law<OrdError>
+where
+    T: Ord + Clone + fmt::Debug,
+{
+    // Values which compare equal may still be distinguishable, so we compare
+    // `Debug` renderings to tell which argument was returned.
+    let same = |x: &T, y: &T| x == y && format!("{:?}", x) == format!("{:?}", y);
+    let cmp = a.cmp(b);
+
+    let max = a.clone().max(b.clone());
+    let expected = if cmp == Ordering::Greater { a } else { b };
+    if !same(&max, expected) {
+        return Err(Violation::new(OrdError::MaxDiffersFromDefault)
+            .observe("a.cmp(b)", cmp)
+            .observe("a.max(b)", max)
+            .observe("expected", expected));
+    }
+    let min = a.clone().min(b.clone());
+    let expected = if cmp == Ordering::Greater { b } else { a };
+    if !same(&min, expected) {
+        return Err(Violation::new(OrdError::MinDiffersFromDefault)
+            .observe("a.cmp(b)", cmp)
+            .observe("a.min(b)", min)
+            .observe("expected", expected));
+    }
+
+    let (low, high) = if b.cmp(c) == Ordering::Greater {
+        (c, b)
+    } else {
+        (b, c)
+    };
+    let clamped = a.clone().clamp(low.clone(), high.clone());
+    let expected = if a.cmp(low) == Ordering::Less {
+        low
+    } else if a.cmp(high) == Ordering::Greater {
+        high
+    } else {
+        a
+    };
+    if !same(&clamped, expected) {
+        return Err(Violation::new(OrdError::ClampDiffersFromDefault)
+            .observe("min", low)
+            .observe("max", high)
+            .observe("a.clamp(min, max)", clamped)
+            .observe("expected", expected));
+    }
+    // Without unwinding, the panic would abort the whole process.
+    #[cfg(not(panic = "abort"))]
+    if low < high && !panics(|| drop(a.clone().clamp(high.clone(), low.clone()))) {
+        return Err(Violation::new(OrdError::ClampDidNotPanic)
+            .observe("min", high)
+            .observe("max", low)
+            .observe("a.clamp(min, max) panicked", false));
+    }
+
+    Ok(())
+}
+
+pub fn ord_matches_key<T, K>(a: &T, b: &T, key: impl Fn(&T) -> K) -> Law<OrdError>
+where
+    T: Ord,
+    K: Ord,
+{
+    let (actual, expected) = (a.cmp(b), key(a).cmp(&key(b)));
+    if actual != expected {
+        return Err(Violation::new(OrdError::DisagreesWithKey)
+            .observe("a.cmp(b)", actual)
+            .observe("key(a).cmp(&key(b))", expected));
+    }
+
+    Ok(())
+}
+
+pub fn partial_ord_matches_key<T, K>(a: &T, b: &T, key: impl Fn(&T) -> K) -> Law<PartialOrdError>
+where
+    T: PartialOrd,
+    K: PartialOrd,
+{
+    let (actual, expected) = (a.partial_cmp(b), key(a).partial_cmp(&key(b)));
+    if actual != expected {
+        return Err(Violation::new(PartialOrdError::DisagreesWithKey)
+            .observe("a.partial_cmp(b)", actual)
+            .observe("key(a).partial_cmp(&key(b))", expected));
+    }
+
+    Ok(())
+}
+
+pub fn eq_matches_key<T, K>(a: &T, b: &T, key: impl Fn(&T) -> K) -> Law<PartialEqError>
+where
+    T: PartialEq,
+    K: PartialEq,
+{
+    let (actual, expected) = (a == b, key(a) == key(b));
+    if actual != expected {
+        return Err(Violation::new(PartialEqError::DisagreesWithKey)
+            .observe("a == b", actual)
+            .observe("key(a) == key(b)", expected));
+    }
+
+    Ok(())
+}
+
+pub fn monotone<T, U>(f: impl Fn(&T) -> U, a: &T, b: &T) -> Law<MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+{
+    preserves_order(
+        f,
+        a,
+        b,
+        ("<=", T::le),
+        ("<=", U::le),
+        MonotonicityError::NotMonotone,
+    )
+}
+
+pub fn strictly_monotone<T, U>(f: impl Fn(&T) -> U, a: &T, b: &T) -> Law<MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+{
+    preserves_order(
+        f,
+        a,
+        b,
+        ("<", T::lt),
+        ("<", U::lt),
+        MonotonicityError::NotStrictlyMonotone,
+    )
+}
+
+pub fn antitone<T, U>(f: impl Fn(&T) -> U, a: &T, b: &T) -> Law<MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+{
+    preserves_order(
+        f,
+        a,
+        b,
+        ("<=", T::le),
+        (">=", U::ge),
+        MonotonicityError::NotAntitone,
+    )
+}
+
+/// Checks that `x R y` implies `f(x) S f(y)` for both `(x, y) = (a, b)` and
+/// `(x, y) = (b, a)`, so that callers don't need to order their inputs.
+fn preserves_order<T, U>(
+    f: impl Fn(&T) -> U,
+    a: &T,
+    b: &T,
+    (r, premise): (&str, fn(&T, &T) -> bool),
+    (s, conclusion): (&str, fn(&U, &U) -> bool),
+    error: MonotonicityError,
+) -> Law<MonotonicityError> {
+    let (fa, fb) = (f(a), f(b));
+    for (x, y, vx, vy, fx, fy) in [("a", "b", a, b, &fa, &fb), ("b", "a", b, a, &fb, &fa)] {
+        if premise(vx, vy) && !conclusion(fx, fy) {
+            return Err(Violation::new(error)
+                .observe(format!("{} {} {}", x, r, y), true)
+                .observe(format!("f({}) {} f({})", x, s, y), false));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn ord_agrees_with<T>(a: &T, b: &T, old_cmp: impl Fn(&T, &T) -> Ordering) -> Law<OrdError>
+where
+    T: Ord,
+{
+    let (actual, expected) = (a.cmp(b), old_cmp(a, b));
+    if actual != expected {
+        return Err(Violation::new(OrdError::DisagreesWithOracle)
+            .observe("a.cmp(b)", actual)
+            .observe("old_cmp(a, b)", expected));
+    }
+
+    Ok(())
+}
+
+pub fn partial_eq_agrees_with<T>(
+    a: &T,
+    b: &T,
+    old_eq: impl Fn(&T, &T) -> bool,
+) -> Law<PartialEqError>
+where
+    T: PartialEq,
+{
+    let (actual, expected) = (a == b, old_eq(a, b));
+    if actual != expected {
+        return Err(Violation::new(PartialEqError::DisagreesWithOracle)
+            .observe("a == b", actual)
+            .observe("old_eq(a, b)", expected));
+    }
+
+    Ok(())
+}
+
+pub fn hash_agrees_with<K>(a: &K, old_hash: impl Fn(&K, &mut dyn Hasher)) -> Law<HashError>
+where
+    K: Hash + ?Sized,
+{
+    let actual = hasher::trace(a);
+    let expected = {
+        let mut hasher = RecordingHasher::new();
+        old_hash(a, &mut hasher);
+        hasher.into_writes()
+    };
+    if hasher::output(&actual) != hasher::output(&expected) {
+        let violation = Violation::new(HashError::DisagreesWithOracle)
+            .observe("hasher_output(a)", hasher::output(&actual))
+            .observe("hasher_output(old_hash, a)", hasher::output(&expected));
+        return Err(observe_diff(violation, &actual, &expected));
+    }
+
+    Ok(())
+}
+
+pub fn partial_eq_purity<T>(a: &T, b: &T) -> Law<PartialEqError>
+where
+    T: PartialEq,
+{
+    purity(
+        PartialEqError::Impure,
+        "a == b",
+        || a == b,
+        || {
+            let _ = (b == a, a == a, a != b);
+        },
+    )
+}
+
+pub fn partial_ord_purity<T>(a: &T, b: &T) -> Law<PartialOrdError>
+where
+    T: PartialOrd,
+{
+    purity(
+        PartialOrdError::Impure,
+        "a.partial_cmp(b)",
+        || a.partial_cmp(b),
+        || {
+            let _ = (b.partial_cmp(a), a.partial_cmp(a), a < b, b >= a, a == b);
+        },
+    )
+}
+
+pub fn ord_purity<T>(a: &T, b: &T) -> Law<OrdError>
+where
+    T: Ord,
+{
+    purity(
+        OrdError::Impure,
+        "a.cmp(b)",
+        || a.cmp(b),
+        || {
+            let _ = (b.cmp(a), a.cmp(a), a.partial_cmp(b), a == b);
+        },
+    )
+}
+
+pub fn hash_purity<K>(a: &K, b: &K) -> Law<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    let trace_a = hasher::trace(a);
+    let again = (0..PURITY_ROUNDS).find_map(|_| {
+        let _ = (a == b, hasher::trace(b), b == a, hasher::trace(a));
+        let again = hasher::trace(a);
+        if hasher::output(&again) != hasher::output(&trace_a) {
+            Some(again)
+        } else {
+            None
+        }
+    });
+
+    match again {
+        Some(again) => {
+            let violation = Violation::new(HashError::Impure)
+                .observe("hasher_output(a)", hasher::output(&trace_a))
+                .observe("hasher_output(a) again", hasher::output(&again));
+            Err(observe_diff(violation, &trace_a, &again))
+        }
+        None => Ok(()),
+    }
+}
+
+/// How many times the purity laws repeat each call, interleaved with other
+/// calls.
+const PURITY_ROUNDS: usize = 3;
+
+/// Checks that `call` always returns the same result, even after `interleave`
+/// has made other calls on the same values.
+fn purity<E, R>(error: E, expr: &str, call: impl Fn() -> R, interleave: impl Fn()) -> Law<E>
+where
+    R: PartialEq + fmt::Debug,
+{
+    let first = call();
+    let again = (0..PURITY_ROUNDS).find_map(|_| {
+        interleave();
+        let again = call();
+        if again != first {
+            Some(again)
+        } else {
+            None
+        }
+    });
+
+    match again {
+        Some(again) => Err(Violation::new(error)
+            .observe(expr, first)
+            .observe(format!("{} again", expr), again)),
+        None => Ok(()),
+    }
+}
+
+pub fn hash_consistency_with_eq<K>(a: &K, b: &K) -> Law<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    let (trace_a, trace_b) = (hasher::trace(a), hasher::trace(b));
+    let hasher_output_a = hasher::output(&trace_a);
+    let hasher_output_b = hasher::output(&trace_b);
+    let equality = a == b;
+
+    let error = match (equality, hasher_output_a == hasher_output_b) {
+        (true, false) => HashError::EqualButDifferentHashes,
+        (false, true) => HashError::UnequalButSameHashes,
+        _ => return Ok(()),
+    };
+
+    let violation = Violation::new(error)
+        .observe("a == b", equality)
+        .observe("hasher_output(a)", hasher_output_a)
+        .observe("hasher_output(b)", hasher_output_b);
+    Err(observe_diff(violation, &trace_a, &trace_b))
+}
+
+pub fn hash_consistency_with_eq_under<K>(a: &K, b: &K, kind: HasherKind) -> Law<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    if a == b {
+        let (hash_a, hash_b) = (kind.hash(a), kind.hash(b));
+        if hash_a != hash_b {
+            return Err(Violation::new(HashError::EqualButDifferentHashes)
+                .observe("hasher", format_args!("{}", kind))
+                .observe("a == b", true)
+                .observe("hash(a)", hash_a)
+                .observe("hash(b)", hash_b));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn hash_determinism<K>(a: &K, copies: impl Fn() -> K, copy: &str) -> Law<HashError>
+where
+    K: Hash,
+{
+    let trace_a = hasher::trace(a);
+    let check = |error, expr: &str, trace: Vec<HashWrite>| {
+        if hasher::output(&trace) == hasher::output(&trace_a) {
+            return Ok(());
+        }
+        let violation = Violation::new(error)
+            .observe("hasher_output(a)", hasher::output(&trace_a))
+            .observe(expr, hasher::output(&trace));
+        Err(observe_diff(violation, &trace_a, &trace))
+    };
+
+    for _ in 0..HASH_ROUNDS {
+        check(
+            HashError::Nondeterministic,
+            "hasher_output(a) again",
+            hasher::trace(a),
+        )?;
+    }
+    for _ in 0..HASH_ROUNDS {
+        let trace = hasher::trace(&copies());
+        check(
+            HashError::DiffersFromCopy,
+            &format!("hasher_output({})", copy),
+            trace,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// How many times [`hash_determinism`] hashes each value and its copies, as
+/// e.g. the iteration order of [`HashSet`](std::collections::HashSet)s only
+/// differs *sometimes*.
+const HASH_ROUNDS: usize = 8;
+
+pub fn hash_prefix_collision<K>(a: &K, b: &K) -> Law<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    if a != b {
+        let (trace_a, trace_b) = (hasher::trace(a), hasher::trace(b));
+        let hasher_output_a = hasher::output(&trace_a);
+        let hasher_output_b = hasher::output(&trace_b);
+
+        // Identical outputs are reported by `hash_consistency_with_eq`.
+        if hasher_output_a != hasher_output_b
+            && either_is_prefix(&hasher_output_a, &hasher_output_b)
+        {
+            let violation = Violation::new(HashError::PrefixCollision)
+                .observe("a == b", false)
+                .observe("hasher_output(a)", hasher_output_a)
+                .observe("hasher_output(b)", hasher_output_b);
+            return Err(observe_diff(violation, &trace_a, &trace_b));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn hash_pair_concatenation<K>((a, b): (&K, &K), (c, d): (&K, &K)) -> Law<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    if a == c && b == d {
+        return Ok(());
+    }
+
+    concatenation_collision(
+        &[a, b],
+        &[c, d],
+        (
+            "(a, b) == (c, d)",
+            "hasher_output(a, b)",
+            "hasher_output(c, d)",
+        ),
+    )
+}
+
+pub fn hash_slice_concatenation<K>(a: &[K], b: &[K]) -> Law<HashError>
+where
+    K: Hash + Eq,
+{
+    if a == b {
+        return Ok(());
+    }
+
+    concatenation_collision(
+        &a.iter().collect::<Vec<_>>(),
+        &b.iter().collect::<Vec<_>>(),
+        (
+            "a == b",
+            "hasher_output(a[0], a[1], ..)",
+            "hasher_output(b[0], b[1], ..)",
+        ),
+    )
+}
+
+/// Checks that hashing all of `a` and all of `b` into two hashers, one value
+/// after the other, doesn't write the same data to both.
+fn concatenation_collision<K>(
+    a: &[&K],
+    b: &[&K],
+    (equality, output_a, output_b): (&str, &str, &str),
+) -> Law<HashError>
+where
+    K: Hash + ?Sized,
+{
+    let trace = |values: &[&K]| {
+        let mut hasher = RecordingHasher::new();
+        for value in values {
+            value.hash(&mut hasher);
+        }
+        hasher.into_writes()
+    };
+
+    let (trace_a, trace_b) = (trace(a), trace(b));
+    if hasher::output(&trace_a) == hasher::output(&trace_b) {
+        let violation = Violation::new(HashError::ConcatenationCollision)
+            .observe(equality, false)
+            .observe(output_a, hasher::output(&trace_a))
+            .observe(output_b, hasher::output(&trace_b));
+        return Err(observe_diff(violation, &trace_a, &trace_b));
+    }
+
+    Ok(())
+}
+
+pub fn borrow_eq<K, Q>(a: &K, b: &K) -> Law<BorrowError>
+where
+    K: Borrow<Q> + Eq,
+    Q: Eq + ?Sized,
+{
+    let (owned, borrowed) = (a == b, a.borrow() == b.borrow());
+    if owned != borrowed {
+        return Err(Violation::new(BorrowError::BadEq)
+            .observe("a == b", owned)
+            .observe("a.borrow() == b.borrow()", borrowed));
+    }
+
+    Ok(())
+}
+
+pub fn borrow_hash<K, Q>(a: &K) -> Law<BorrowError>
+where
+    K: Borrow<Q> + Hash,
+    Q: Hash + ?Sized,
+{
+    let (owned, borrowed) = (hasher::trace(a), hasher::trace(a.borrow()));
+    if hasher::output(&owned) != hasher::output(&borrowed) {
+        let violation = Violation::new(BorrowError::BadHash)
+            .observe("hasher_output(a)", hasher::output(&owned))
+            .observe("hasher_output(a.borrow())", hasher::output(&borrowed));
+        return Err(observe_diff(violation, &owned, &borrowed));
+    }
+
+    Ok(())
+}
+
+pub fn borrow_ord<K, Q>(a: &K, b: &K) -> Law<BorrowError>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    let (owned, borrowed) = (a.cmp(b), a.borrow().cmp(b.borrow()));
+    if owned != borrowed {
+        return Err(Violation::new(BorrowError::BadCmp)
+            .observe("a.cmp(b)", owned)
+            .observe("a.borrow().cmp(b.borrow())", borrowed));
+    }
+
+    Ok(())
+}
+
+#[cfg(any(feature = "hashbrown", feature = "indexmap"))]
+pub fn equivalent_hash<Q, K>(
+    q: &Q,
+    a: &K,
+    equivalent: impl Fn(&Q, &K) -> bool,
+) -> Law<EquivalentError>
+where
+    Q: Hash + ?Sized,
+    K: Hash + ?Sized,
+{
+    if equivalent(q, a) {
+        let (trace_q, trace_a) = (hasher::trace(q), hasher::trace(a));
+        if hasher::output(&trace_q) != hasher::output(&trace_a) {
+            let violation = Violation::new(EquivalentError::BadHash)
+                .observe("q.equivalent(a)", true)
+                .observe("hasher_output(q)", hasher::output(&trace_q))
+                .observe("hasher_output(a)", hasher::output(&trace_a));
+            return Err(observe_diff(violation, &trace_q, &trace_a));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(any(feature = "hashbrown", feature = "indexmap"))]
+pub fn equivalent_eq<Q, K>(
+    q: &Q,
+    a: &K,
+    b: &K,
+    equivalent: impl Fn(&Q, &K) -> bool,
+) -> Law<EquivalentError>
+where
+    Q: ?Sized,
+    K: Eq + ?Sized,
+{
+    if equivalent(q, a) {
+        let (eq, equivalent_b) = (a == b, equivalent(q, b));
+        if eq != equivalent_b {
+            return Err(Violation::new(EquivalentError::BadEq)
+                .observe("q.equivalent(a)", true)
+                .observe("a == b", eq)
+                .observe("q.equivalent(b)", equivalent_b));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn hash_slice_consistency_with_eq<T>(a: &T, b: &T, c: &T) -> Law<HashError>
+where
+    T: Hash + Eq + Clone,
+{
+    // Distinct `a`, `b`, and `c` make all permutations unequal, so each of
+    // them is also compared against an independent clone of itself.
+    let (slices, clones) = (permutations(a, b, c), permutations(a, b, c));
+    let mut pairs = vec![];
+    for (n, (name_x, x)) in slices.iter().enumerate() {
+        let (name_clone, clone) = &clones[n];
+        pairs.push((name_x, x, format!("{}.clone()", name_clone), clone));
+        for (name_y, y) in &slices[n + 1..] {
+            pairs.push((name_x, x, name_y.clone(), y));
+        }
+    }
+
+    // Equal slices with different outputs are reported before unequal slices
+    // with identical outputs, which are only a quality issue.
+    let mut collision = None;
+    for (name_x, x, name_y, y) in pairs {
+        let (trace_x, trace_y) = (hash_slice_trace(x), hash_slice_trace(y));
+        let equality = x == y;
+        let error = match (
+            equality,
+            hasher::output(&trace_x) == hasher::output(&trace_y),
+        ) {
+            (true, false) => HashError::HashSliceEqualButDifferentHashes,
+            (false, true) => HashError::HashSliceUnequalButSameHashes,
+            _ => continue,
+        };
+
+        let violation = Violation::new(error)
+            .observe(format!("{} == {}", name_x, name_y), equality)
+            .observe(
+                format!("hasher_output(T::hash_slice(&{}))", name_x),
+                hasher::output(&trace_x),
+            )
+            .observe(
+                format!("hasher_output(T::hash_slice(&{}))", name_y),
+                hasher::output(&trace_y),
+            );
+        let violation = observe_diff(violation, &trace_x, &trace_y);
+        if equality {
+            return Err(violation);
+        }
+        collision.get_or_insert(violation);
+    }
+
+    match collision {
+        Some(violation) => Err(violation),
+        None => Ok(()),
+    }
+}
+
+pub fn hash_slice_elementwise<T>(a: &T, b: &T, c: &T) -> Law<HashError>
+where
+    T: Hash + Clone,
+{
+    for (name, slice) in permutations(a, b, c) {
+        let trace = hash_slice_trace(&slice);
+        let elementwise = {
+            let mut hasher = RecordingHasher::new();
+            for value in &slice {
+                value.hash(&mut hasher);
+            }
+            hasher.into_writes()
+        };
+        if hasher::output(&trace) != hasher::output(&elementwise) {
+            let violation = Violation::new(HashError::HashSliceDiffersFromElements)
+                .observe(
+                    format!("hasher_output(T::hash_slice(&{}))", name),
+                    hasher::output(&trace),
+                )
+                .observe(
+                    format!("hasher_output of each element of {}", name),
+                    hasher::output(&elementwise),
+                );
+            return Err(observe_diff(violation, &trace, &elementwise));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns all permutations of `[a, b, c]`, together with their names (e.g.
+/// `"[b, a, c]"`).
+fn permutations<T>(a: &T, b: &T, c: &T) -> Vec<(String, [T; 3])>
+where
+    T: Clone,
+{
+    let values = [("a", a), ("b", b), ("c", c)];
+    [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ]
+    .iter()
+    .map(|&[i, j, k]| {
+        let name = format!("[{}, {}, {}]", values[i].0, values[j].0, values[k].0);
+        let slice = [
+            values[i].1.clone(),
+            values[j].1.clone(),
+            values[k].1.clone(),
+        ];
+        (name, slice)
+    })
+    .collect()
+}
+
+fn hash_slice_trace<T>(slice: &[T]) -> Vec<HashWrite>
+where
+    T: Hash,
+{
+    let mut hasher = RecordingHasher::new();
+    T::hash_slice(slice, &mut hasher);
+    hasher.into_writes()
+}
+
+pub fn ord_preserving_encoding<T, E>(a: &T, b: &T, encode: impl Fn(&T) -> E) -> Law<EncodingError>
+where
+    T: Ord,
+    E: AsRef<[u8]>,
+{
+    let (encoding_a, encoding_b) = (encode(a), encode(b));
+    let (encoding_a, encoding_b) = (encoding_a.as_ref(), encoding_b.as_ref());
+
+    let (actual, expected) = (encoding_a.cmp(encoding_b), a.cmp(b));
+    if actual != expected {
+        return Err(Violation::new(EncodingError::BadOrder)
+            .observe("a.cmp(b)", expected)
+            .observe("encode(a).cmp(&encode(b))", actual)
+            .observe("encode(a)", encoding_a)
+            .observe("encode(b)", encoding_b));
+    }
+
+    if a != b && either_is_prefix(encoding_a, encoding_b) {
+        return Err(Violation::new(EncodingError::PrefixCollision)
+            .observe("a == b", false)
+            .observe("encode(a)", encoding_a)
+            .observe("encode(b)", encoding_b));
+    }
+
+    Ok(())
+}
+
+pub fn clone_consistency_with_eq<T>(a: &T) -> Law<CloneError>
+where
+    T: Clone + PartialEq,
+{
+    let clone = a.clone();
+    let (left, right) = (clone == *a, *a == clone);
+    if !(left && right) {
+        return Err(Violation::new(CloneError::NotEqual)
+            .observe("a.clone() == a", left)
+            .observe("a == a.clone()", right));
+    }
+
+    Ok(())
+}
+
+pub fn clone_consistency_with_ord<T>(a: &T) -> Law<CloneError>
+where
+    T: Clone + Ord,
+{
+    let clone = a.clone();
+    let (left, right) = (clone.cmp(a), a.cmp(&clone));
+    if left != Ordering::Equal || right != Ordering::Equal {
+        return Err(Violation::new(CloneError::CmpNotEqual)
+            .observe("a.clone().cmp(&a)", left)
+            .observe("a.cmp(&a.clone())", right));
+    }
+
+    Ok(())
+}
+
+pub fn clone_consistency_with_hash<T>(a: &T, b: &T) -> Law<CloneError>
+where
+    T: Clone + Hash,
+{
+    let trace_a = hasher::trace(a);
+    let mut clone_from = b.clone();
+    clone_from.clone_from(a);
+
+    for (expr, trace) in [
+        ("hasher_output(a.clone())", hasher::trace(&a.clone())),
+        (
+            "hasher_output(b.clone_from(&a))",
+            hasher::trace(&clone_from),
+        ),
+    ] {
+        if hasher::output(&trace) != hasher::output(&trace_a) {
+            let violation = Violation::new(CloneError::DifferentHashes)
+                .observe("hasher_output(a)", hasher::output(&trace_a))
+                .observe(expr, hasher::output(&trace));
+            return Err(observe_diff(violation, &trace_a, &trace));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn clone_from_consistency<T>(a: &T, b: &T) -> Law<CloneError>
+where
+    T: Clone + PartialEq,
+{
+    let mut clone_from = b.clone();
+    clone_from.clone_from(a);
+    if clone_from != *a {
+        return Err(Violation::new(CloneError::BadCloneFrom)
+            .observe("b.clone_from(&a); b == a", false)
+            .observe("a.clone() == a", a.clone() == *a));
+    }
+
+    Ok(())
+}
+
+pub fn iterator_size_hint<I>(iter: I) -> Law<IteratorError>
+where
+    I: Iterator,
+{
+    let size_hint = iter.size_hint();
+    let count = iter.count();
+    let violation = || {
+        Err(Violation::new(IteratorError::BadSizeHint)
+            .observe("iter.size_hint()", size_hint)
+            .observe("iter.count()", count))
+    };
+
+    if size_hint.0 > count {
+        return violation();
+    } else if let Some(upper_bound) = size_hint.1 {
+        if upper_bound < count {
+            return violation();
+        }
+    }
+
+    Ok(())
+}
+
+pub fn iterator_count<I>(iter: I) -> Law<IteratorError>
+where
+    I: Iterator + Clone,
+{
+    let count = iter.clone().count();
+    let collected = iter.collect::<Vec<_>>();
+
+    if count != collected.len() {
+        return Err(Violation::new(IteratorError::BadCount)
+            .observe("iter.count()", count)
+            .observe("iter.collect::<Vec<_>>().len()", collected.len()));
+    }
+
+    Ok(())
+}
+
+pub fn iterator_last<I>(iter: I) -> Law<IteratorError>
+where
+    I: Iterator + Clone,
+    I::Item: PartialEq,
+{
+    let last = iter.clone().last();
+    let collected = iter.collect::<Vec<_>>();
+
+    if last.as_ref() != collected.last() {
+        return Err(Violation::new(IteratorError::BadLast)
+            .observe("iter.last().is_some()", last.is_some())
+            .observe("iter.collect::<Vec<_>>().len()", collected.len()));
+    }
+
+    Ok(())
+}
+
+pub fn double_ended_iterator_next_back<I>(mut iter: I) -> Law<IteratorError>
+where
+    I: DoubleEndedIterator + Clone,
+    I::Item: PartialEq,
+{
+    let collected = iter.clone().collect::<Vec<_>>();
+
+    let mut from_start = vec![];
+    let mut from_end = vec![];
+    loop {
+        if rand::random() {
+            if let Some(item) = iter.next() {
+                from_start.push(item);
+            } else {
+                break;
+            }
+        } else if let Some(item) = iter.next_back() {
+            from_end.push(item);
+        } else {
+            break;
+        }
+    }
+
+    let (next_count, next_back_count) = (from_start.len(), from_end.len());
+    let assembled = from_start
+        .into_iter()
+        .chain(from_end.into_iter().rev())
+        .collect::<Vec<_>>();
+
+    if assembled != collected {
+        return Err(Violation::new(IteratorError::BadNextBack)
+            .observe("items from iter.next()", next_count)
+            .observe("items from iter.next_back()", next_back_count)
+            .observe("iter.collect::<Vec<_>>().len()", collected.len()));
+    }
+
+    Ok(())
+}
+
+pub fn fused_iterator_none_forever<I>(mut iter: I) -> Law<IteratorError>
+where
+    I: FusedIterator + Clone,
+{
+    let mut count = 0;
+    while iter.next().is_some() {
+        count += 1;
+    }
+
+    // How many times does it make sense to keep going to have decent confidence
+    // it will return `None` forever? Hard to say. I'm going with .count() + 1
+    // in case the iterator "goes back" or something.
+    for i in 0..count + 1 {
+        if iter.next().is_some() {
+            return Err(
+                Violation::new(IteratorError::FusedIteratorReturnedSomeAfterExhaustion)
+                    .observe("items before the first None", count)
+                    .observe("calls to iter.next() after the first None", i + 1),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The results of all comparison operators between two values.
+struct Relations {
+    lt: bool,
+    le: bool,
+    eq: bool,
+    ge: bool,
+    gt: bool,
+}
+
+impl Relations {
+    fn of<A, B>(a: &A, b: &B) -> Self
+    where
+        A: PartialOrd<B>,
+    {
+        Self {
+            lt: a < b,
+            le: a <= b,
+            eq: a == b,
+            ge: a >= b,
+            gt: a > b,
+        }
+    }
+
+    fn get(&self, relation: Relation) -> bool {
+        match relation {
+            Relation::Lt => self.lt,
+            Relation::Le => self.le,
+            Relation::Eq => self.eq,
+            Relation::Ge => self.ge,
+            Relation::Gt => self.gt,
+        }
+    }
+}
+
+/// A comparison operator, as looked up in [`Relations`].
+#[derive(Clone, Copy)]
+enum Relation {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Relation {
+    fn operator(self) -> &'static str {
+        match self {
+            Relation::Lt => "<",
+            Relation::Le => "<=",
+            Relation::Eq => "==",
+            Relation::Ge => ">=",
+            Relation::Gt => ">",
+        }
+    }
+}
+
+/// Returns [`true`] if `f` panics.
+///
+/// The panic hook is left alone, so the panic message is still printed.
+#[cfg(not(panic = "abort"))]
+fn panics(f: impl FnOnce()) -> bool {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).is_err()
+}
+
+fn either_is_prefix(a: &[u8], b: &[u8]) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
+
+pub fn hasher_output<K>(item: &K) -> Vec<u8>
+where
+    K: Hash + ?Sized,
+{
+    hasher::output(&hasher::trace(item))
+}
+
+/// Points to the first write at which the traces of two values diverge, if
+/// any.
+fn observe_diff<E>(
+    violation: Violation<E>,
+    trace_a: &[HashWrite],
+    trace_b: &[HashWrite],
+) -> Violation<E> {
+    match hasher::diff(trace_a, trace_b) {
+        Some(diff) => violation.observe("first divergent write", format_args!("{}", diff)),
+        None => violation,
+    }
+}
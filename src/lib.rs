@@ -39,10 +39,30 @@
 //! 2. Based on the traits that your type implements, call the appropriate checker(s):
 //!
 //!    - [`reltester::eq`](eq) for [`Eq`];
-//!    - [`reltester::ord`](ord) for [`Ord`];
+//!    - [`reltester::ord`](ord) for [`Ord`], and
+//!      [`reltester::ord_exact`](ord_exact) for overrides of [`Ord::max`],
+//!      [`Ord::min`], and [`Ord::clamp`];
 //!    - [`reltester::partial_eq`](partial_eq) for [`PartialEq`];
 //!    - [`reltester::partial_ord`](partial_ord) for [`PartialOrd`];
-//!    - [`reltester::hash`](hash) for [`Hash`];
+//!    - [`reltester::strict_weak_order`](strict_weak_order) for [`PartialOrd`]
+//!      implementations used for sorting;
+//!    - [`reltester::hash`](hash) for [`Hash`],
+//!      [`reltester::hash_concatenation`](hash_concatenation) for values
+//!      which are hashed together, e.g. as fields of the same struct,
+//!      [`reltester::hash_determinism`](hash_determinism) for values which
+//!      contain pointers or unordered collections, and
+//!      [`reltester::hash_slice`](hash_slice) for overrides of
+//!      [`Hash::hash_slice`];
+//!    - [`reltester::borrow_hash`](borrow_hash) and
+//!      [`reltester::borrow_ord`](borrow_ord) for [`Borrow`] implementations
+//!      of [`HashMap`](std::collections::HashMap) and
+//!      [`BTreeMap`](std::collections::BTreeMap) keys;
+//!    - [`reltester::clone`](clone), [`reltester::clone_ord`](clone_ord), and
+//!      [`reltester::clone_hash`](clone_hash) for [`Clone`];
+//!    - [`reltester::comparator`](comparator),
+//!      [`reltester::partial_comparator`](partial_comparator), and
+//!      [`reltester::equivalence`](equivalence) for closures passed to e.g.
+//!      [`slice::sort_by`];
 //!    - [`reltester::iterator`](iterator) for [`Iterator`];
 //!    - [`reltester::fused_iterator`](fused_iterator) for [`FusedIterator`];
 //!    - [`reltester::double_ended_iterator`](double_ended_iterator) for [`DoubleEndedIterator`];
@@ -54,6 +74,89 @@
 //! The [`reltester::invariants`](invariants) module is available for more
 //! granular checks if you can't satisfy the type bounds of the main functions.
 //!
+//! ## Explaining failures
+//!
+//! Knowing *which* invariant broke is often not enough to track down a bug.
+//! If your type implements [`Debug`](std::fmt::Debug), the `*_verbose`
+//! variants of the checkers (e.g. [`reltester::ord_verbose`](ord_verbose))
+//! return a [`Violation`](error::Violation) which also records the offending
+//! values and the results that the broken invariant observed:
+//!
+//! ```text
+//! a == b && b == c MUST imply a == c
+//!     a = Fuzzy(0)
+//!     b = Fuzzy(1)
+//!     c = Fuzzy(2)
+//!     where a == b: true, b == c: true, a == c: false
+//! ```
+//!
+//! All checkers stop at the first broken invariant. Use the `*_report`
+//! variants (e.g. [`reltester::ord_report`](ord_report)) to check all
+//! invariants and get a [`Report`](error::Report) of every
+//! [`Violation`](error::Violation) instead. These don't require
+//! [`Debug`](std::fmt::Debug), so their violations record the observed
+//! results but not the values.
+//!
+//! Broken [`Hash`] invariants also point to the first write at which the two
+//! values fed different data to the hasher, e.g. `first divergent write:
+//! writes[1]: write_u32(1) vs write_u32(2)`. The [`hasher`] module exposes the
+//! recording [`Hasher`] behind this, should you need to inspect the writes of
+//! a [`Hash`] implementation yourself, as well as the actual hashers which
+//! [`hash_under`] runs the checks with.
+//!
+//! ## Checking whole samples
+//!
+//! Checking a handful of triples at a time rarely satisfies the premises of
+//! invariants such as transitivity. [`ord_sample`], [`partial_ord_sample`],
+//! [`eq_sample`], and [`hash_sample`] instead check the invariants across a
+//! whole slice of values. [`ord_sample`] and [`partial_ord_sample`] sort the
+//! values first, so that samples of tens of thousands of values remain cheap
+//! to check, while [`eq_sample`] and [`hash_sample`] group values by equality,
+//! which takes `O(n k)` comparisons where `k` is the number of distinct
+//! values. For smaller samples,
+//! [`partial_ord_cycles`] also finds broken transitivity chains of any length
+//! and [`eq_classes`] checks that `==` partitions the sample into equivalence
+//! classes:
+//!
+//! ```rust
+//! use reltester;
+//! use quickcheck_macros::quickcheck;
+//!
+//! #[quickcheck]
+//! fn test_u32_sample(nums: Vec<u32>) -> bool {
+//!     reltester::ord_sample(&nums).is_ok() && reltester::hash_sample(&nums).is_ok()
+//! }
+//! ```
+//!
+//! ## Checking against a specification
+//!
+//! All of the above only check that your implementations are internally
+//! consistent. If your [`Ord`], [`PartialOrd`], or [`PartialEq`]
+//! implementation is meant to compare values by some key, use
+//! [`ord_matches_key`], [`partial_ord_matches_key`], or [`eq_matches_key`] to
+//! check that it does exactly that.
+//!
+//! When rewriting an implementation, [`ord_agrees_with`],
+//! [`partial_eq_agrees_with`], and [`hash_agrees_with`] check it against the
+//! old one.
+//!
+//! The same goes for functions between ordered types: [`monotone`],
+//! [`strictly_monotone`], and [`antitone`] check that they preserve (or
+//! reverse) the order of their inputs (their `_verbose` counterparts also
+//! report the values and their images), and [`ord_preserving_encoding`] checks
+//! byte encodings of keys which must sort like the original values.
+//!
+//! ## Optional features
+//!
+//! - `hashbrown`: enables [`hashbrown_equivalent`], which checks
+//!   `hashbrown::Equivalent` implementations used for heterogeneous lookups.
+//! - `indexmap`: enables [`indexmap_equivalent`], which does the same for
+//!   `indexmap::Equivalent`.
+//!
+//! Both features depend on crates (`hashbrown` 0.14 and `indexmap` 2) which
+//! require Rust 1.63 or newer, above the minimum supported Rust version of
+//! this crate.
+//!
 //! ## Multi-type relations: `Foo: PartialEq<Bar>` and `Foo: PartialOrd<Bar>`
 //!
 //! In some cases your [`PartialEq`] and [`PartialOrd`] implementations
@@ -122,7 +225,9 @@
 //!   case of `Rhs == Self`).
 //! - [`Eq`] requires **symmetry**, **transitivity**, and **reflexivity** of `==` ([equivalence relation](https://en.wikipedia.org/wiki/Equivalence_relation)).
 //! - [`PartialOrd`] requires **symmetry** of `==`, **transitivity** of `>`,
-//!   `==`, and `<`; and **duality** of `>` and `<`. Note that duality is not
+//!   `==`, and `<` (including chains which mix them, e.g. `a < b && b == c`
+//!   implies `a < c`); **irreflexivity** and **asymmetry** of `>` and `<`;
+//!   **antisymmetry** of `<=`; and **duality** of `>` and `<`. Note that duality is not
 //!   common mathematical
 //!   terminology, it's just what the Rust [`std`] uses to describe `a > b iff b < a`.
 //!   Thus the exact mathematical definition of [`PartialOrd`] seems [open to
@@ -153,24 +258,77 @@
 
 #![allow(clippy::eq_op, clippy::double_comparisons)]
 
+mod adapters;
 pub mod error;
+pub mod hasher;
 pub mod invariants;
+mod laws;
+mod sample;
 
 use error::*;
-use std::{hash::Hash, iter::FusedIterator};
+use hasher::HasherKind;
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
 
 /// Checks the correctness of the [`Ord`] trait (and [`Eq`] and [`PartialOrd`]
 /// by extension) for some values.
+///
+/// Overrides of [`Ord::max`], [`Ord::min`], and [`Ord::clamp`] take their
+/// arguments by value and can't be called on `&T`. If `T` implements [`Clone`]
+/// and [`Debug`](fmt::Debug), use [`ord_exact`] to check them as well.
 pub fn ord<T>(a: &T, b: &T, c: &T) -> Result<(), Error>
 where
     T: Ord,
 {
-    eq(a, b, c)?;
-    partial_ord(a, b, c)?;
+    ord_checks(a, b, c)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    invariants::ord_methods_consistency(a, b, c)?;
+/// Like [`ord`], but the returned [`Violation`] also carries the
+/// [`Debug`](fmt::Debug) renderings of the values and the results that the
+/// broken invariant observed.
+pub fn ord_verbose<T>(a: &T, b: &T, c: &T) -> Result<(), Violation>
+where
+    T: Ord + fmt::Debug,
+{
+    ord_checks(a, b, c)
+        .into_result()
+        .map_err(|v| with_values(v, a, b, c))
+}
+
+/// Like [`ord`], but checks all invariants instead of stopping at the first
+/// broken one.
+pub fn ord_report<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: Ord,
+{
+    ord_checks(a, b, c)
+}
 
-    Ok(())
+/// Like [`ord_verbose`], but also checks that overrides of [`Ord::max`],
+/// [`Ord::min`], and [`Ord::clamp`] return exactly the same values as their
+/// default implementations, including which argument is returned when the two
+/// compare equal, and that [`Ord::clamp`] panics if `min > max`.
+///
+/// These methods are called on clones of the values, and values which compare
+/// equal are told apart by their [`Debug`](fmt::Debug) renderings.
+///
+/// The [`Ord::clamp`] panic is caught with [`std::panic::catch_unwind`], which
+/// requires unwinding: its message is printed by the panic hook as usual, and
+/// with `panic = "abort"` the check is skipped altogether.
+pub fn ord_exact<T>(a: &T, b: &T, c: &T) -> Result<(), Violation>
+where
+    T: Ord + Clone + fmt::Debug,
+{
+    let mut report = ord_checks(a, b, c);
+    report.check(laws::ord_methods_exactness(a, b, c));
+    report.into_result().map_err(|v| with_values(v, a, b, c))
 }
 
 /// Checks the correctness of the [`PartialOrd`] trait (and [`PartialEq`] by
@@ -179,13 +337,54 @@ pub fn partial_ord<T>(a: &T, b: &T, c: &T) -> Result<(), Error>
 where
     T: PartialOrd,
 {
-    partial_eq(a, b, c)?;
+    partial_ord_checks(a, b, c)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    invariants::partial_ord_methods_consistency(a, b)?;
-    invariants::partial_ord_duality(a, b)?;
-    invariants::partial_ord_transitivity(a, b, c)?;
+/// Like [`partial_ord`], but the returned [`Violation`] also carries the
+/// [`Debug`](fmt::Debug) renderings of the values and the results that the
+/// broken invariant observed.
+pub fn partial_ord_verbose<T>(a: &T, b: &T, c: &T) -> Result<(), Violation>
+where
+    T: PartialOrd + fmt::Debug,
+{
+    partial_ord_checks(a, b, c)
+        .into_result()
+        .map_err(|v| with_values(v, a, b, c))
+}
+
+/// Like [`partial_ord`], but checks all invariants instead of stopping at the
+/// first broken one.
+pub fn partial_ord_report<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: PartialOrd,
+{
+    partial_ord_checks(a, b, c)
+}
 
-    Ok(())
+/// Checks that [`PartialOrd`] is a [strict weak
+/// ordering](https://en.wikipedia.org/wiki/Weak_ordering#Strict_weak_orderings)
+/// for some values, which makes it safe to sort with e.g.
+/// `slice.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))`.
+///
+/// Besides all invariants of [`PartialOrd`], strict weak orderings require
+/// incomparable values to be interchangeable: if `a` and `b` are
+/// incomparable, and so are `b` and `c`, then `a` and `c` must be incomparable
+/// too. Sorting algorithms may panic (since Rust 1.81) or return unsorted
+/// results otherwise. Notably, `f32` and `f64` are *not* strict weak orderings
+/// because of `NaN`.
+pub fn strict_weak_order<T>(a: &T, b: &T, c: &T) -> Result<(), Error>
+where
+    T: PartialOrd,
+{
+    let mut report = partial_ord_checks(a, b, c);
+    // Each value takes a turn in the middle of the chain, so that the result
+    // doesn't depend on the order of the arguments.
+    report.check(laws::incomparability_transitivity(b, a, c));
+    report.check(laws::incomparability_transitivity(a, b, c));
+    report.check(laws::incomparability_transitivity(a, c, b));
+    report.into_result().map_err(Violation::into_error)
 }
 
 /// Checks the correctness of the [`Eq`] trait (and [`PartialEq`] by extension)
@@ -198,13 +397,30 @@ pub fn eq<T>(a: &T, b: &T, c: &T) -> Result<(), Error>
 where
     T: PartialEq<T>,
 {
-    partial_eq(a, b, c)?;
+    eq_checks(a, b, c)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    // Checking `Eq` is the same as checking `PartialEq`, except it also
-    // requires reflexivity.
-    invariants::eq_reflexivity(a)?;
+/// Like [`eq`], but the returned [`Violation`] also carries the
+/// [`Debug`](fmt::Debug) renderings of the values and the results that the
+/// broken invariant observed.
+pub fn eq_verbose<T>(a: &T, b: &T, c: &T) -> Result<(), Violation>
+where
+    T: PartialEq<T> + fmt::Debug,
+{
+    eq_checks(a, b, c)
+        .into_result()
+        .map_err(|v| with_values(v, a, b, c))
+}
 
-    Ok(())
+/// Like [`eq`], but checks all invariants instead of stopping at the first
+/// broken one.
+pub fn eq_report<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: PartialEq<T>,
+{
+    eq_checks(a, b, c)
 }
 
 /// Checks the correctness of the [`PartialEq`] trait
@@ -213,23 +429,586 @@ pub fn partial_eq<T>(a: &T, b: &T, c: &T) -> Result<(), PartialEqError>
 where
     T: PartialEq,
 {
-    invariants::partial_eq_methods_consistency(a, b)?;
-    invariants::partial_eq_symmetry(a, b)?;
-    invariants::partial_eq_transitivity(a, b, c)?;
+    partial_eq_checks(a, b, c)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Like [`partial_eq`], but the returned [`Violation`] also carries the
+/// [`Debug`](fmt::Debug) renderings of the values and the results that the
+/// broken invariant observed.
+pub fn partial_eq_verbose<T>(a: &T, b: &T, c: &T) -> Result<(), Violation<PartialEqError>>
+where
+    T: PartialEq + fmt::Debug,
+{
+    partial_eq_checks(a, b, c)
+        .into_result()
+        .map_err(|v| with_values(v, a, b, c))
+}
+
+/// Like [`partial_eq`], but checks all invariants instead of stopping at the
+/// first broken one.
+pub fn partial_eq_report<T>(a: &T, b: &T, c: &T) -> Report<PartialEqError>
+where
+    T: PartialEq,
+{
+    partial_eq_checks(a, b, c)
 }
 
 /// Checks the correctness of the [`Hash`] trait in relation to [`Eq`] for some
 /// values.
+///
+/// The returned [`HashError`] only says which invariant was broken. The first
+/// write at which the hasher outputs of `a` and `b` diverge is only available
+/// through the [`Violation`]s of [`hash_verbose`] and [`hash_report`].
 pub fn hash<K>(a: &K, b: &K) -> Result<(), HashError>
 where
     K: Hash + Eq + ?Sized,
 {
-    invariants::hash_consistency_with_eq(a, b)?;
-    invariants::hash_prefix_collision(a, b)?;
+    hash_checks(a, b)
+        .into_result()
+        .map_err(Violation::into_error)
+}
+
+/// Like [`hash`], but the returned [`Violation`] also carries the
+/// [`Debug`](fmt::Debug) renderings of the values and the hasher outputs that
+/// the broken invariant observed.
+pub fn hash_verbose<K>(a: &K, b: &K) -> Result<(), Violation<HashError>>
+where
+    K: Hash + Eq + fmt::Debug + ?Sized,
+{
+    hash_checks(a, b)
+        .into_result()
+        .map_err(|v| v.with_value("a", a).with_value("b", b))
+}
+
+/// Like [`hash`], but checks all invariants instead of stopping at the first
+/// broken one.
+///
+/// Unequal values with identical hasher outputs are only a quality issue,
+/// which can be treated as a warning with
+/// `hash_report(a, b).downgrade(HashError::is_quality_issue)`.
+pub fn hash_report<K>(a: &K, b: &K) -> Report<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    hash_checks(a, b)
+}
+
+/// Like [`hash`], but also checks that equal values have equal hashes under
+/// each of `hashers`, e.g. [`HasherKind::ALL`](hasher::HasherKind::ALL).
+///
+/// [`hash`] only compares the data written to the hasher, so it misses
+/// [`Hash`] implementations which call [`Hasher::finish`] themselves or which
+/// write the same data with different `write_*` methods. The returned
+/// [`Violation`] names the hasher which exposed the mismatch, if any.
+pub fn hash_under<K>(a: &K, b: &K, hashers: &[HasherKind]) -> Result<(), Violation<HashError>>
+where
+    K: Hash + Eq + ?Sized,
+{
+    let mut report = hash_checks(a, b);
+    for &kind in hashers {
+        report.check(laws::hash_consistency_with_eq_under(a, b, kind));
+    }
+    report.into_result()
+}
+
+/// Checks that the [`Hash`] implementation of `K` is deterministic: hashing `a`
+/// several times, and hashing several clones of it, *MUST* always write the
+/// same data to the hasher.
+///
+/// Clones often share state with the original (e.g. an [`Rc`](std::rc::Rc)
+/// points to the same address, a [`HashSet`](std::collections::HashSet)
+/// keeps its iteration order), so use [`hash_determinism_with`] to catch
+/// [`Hash`] implementations which depend on such state. The returned
+/// [`Violation`] points to the first divergent write.
+pub fn hash_determinism<K>(a: &K) -> Result<(), Violation<HashError>>
+where
+    K: Hash + Clone,
+{
+    laws::hash_determinism(a, || a.clone(), "a.clone()")
+}
+
+/// Like [`hash_determinism`], but instead of clones, checks several values
+/// independently constructed by `make`, which *MUST* all write the same data
+/// to the hasher as `a = make()`.
+pub fn hash_determinism_with<K, F>(make: F) -> Result<(), Violation<HashError>>
+where
+    K: Hash,
+    F: Fn() -> K,
+{
+    laws::hash_determinism(&make(), &make, "make()")
+}
+
+/// Checks the correctness of [`Hash::hash_slice`] for some values, which
+/// types can override (`std` does so for integers) and which `[T]` and
+/// [`Vec<T>`] use to hash their elements.
+///
+/// Equal slices *MUST* have equal [`Hash::hash_slice`] outputs, unequal slices
+/// *SHOULD* have different ones, and those *SHOULD* be the same as hashing
+/// each element in turn. The latter two are only quality issues (see
+/// [`HashError::is_quality_issue`]), so they're reported last. Use
+/// [`hash_slice_report`] to treat them as warnings.
+pub fn hash_slice<T>(a: &T, b: &T, c: &T) -> Result<(), HashError>
+where
+    T: Hash + Eq + Clone,
+{
+    hash_slice_report(a, b, c)
+        .into_result()
+        .map_err(Violation::into_error)
+}
+
+/// Like [`hash_slice`], but checks all invariants instead of stopping at the
+/// first broken one.
+///
+/// Quality issues can be treated as warnings with
+/// `hash_slice_report(a, b, c).downgrade(HashError::is_quality_issue)`.
+pub fn hash_slice_report<T>(a: &T, b: &T, c: &T) -> Report<HashError>
+where
+    T: Hash + Eq + Clone,
+{
+    let mut report = Report::new();
+    report.check(laws::hash_slice_consistency_with_eq(a, b, c));
+    report.check(laws::hash_slice_elementwise(a, b, c));
+    report
+}
+
+/// Checks that two different sequences of values don't write the same data to
+/// the hasher when hashed one value after the other, e.g. that `["ab", "c"]`
+/// and `["a", "bc"]` don't collide in a composite hash.
+///
+/// [`hash`] only checks that the hash output of a value isn't a prefix of
+/// another's, which is necessary but not sufficient for this. Pairs of
+/// borrowed values can be checked with
+/// [`invariants::hash_pair_concatenation`].
+pub fn hash_concatenation<K>(a: &[K], b: &[K]) -> Result<(), HashError>
+where
+    K: Hash + Eq,
+{
+    invariants::hash_slice_concatenation(a, b)
+}
+
+/// Checks that `K: Borrow<Q>` preserves the [`Eq`] and [`Hash`]
+/// implementations of `K`, as required by e.g. [`HashMap::get`]:
+/// `a == b` *MUST* be equal to `a.borrow() == b.borrow()`, and both `a` and
+/// `a.borrow()` *MUST* write the same data to the hasher.
+///
+/// A mismatch makes lookups by `&Q` silently miss entries. Since `Q` can't be
+/// inferred, call this as e.g. `reltester::borrow_hash::<String, str>(&a,
+/// &b)`.
+///
+/// [`HashMap::get`]: std::collections::HashMap::get
+pub fn borrow_hash<K, Q>(a: &K, b: &K) -> Result<(), BorrowError>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + ?Sized,
+{
+    let mut report = Report::<BorrowError>::new();
+    report.check(laws::borrow_eq::<K, Q>(a, b));
+    report.check(laws::borrow_hash::<K, Q>(a));
+    report.check(laws::borrow_hash::<K, Q>(b));
+    report.into_result().map_err(Violation::into_error)
+}
+
+/// Checks that `K: Borrow<Q>` preserves the [`Ord`] (and [`Eq`])
+/// implementation of `K`, as required by e.g. [`BTreeMap::get`]: `a.cmp(b)`
+/// *MUST* be equal to `a.borrow().cmp(b.borrow())`.
+///
+/// See [`borrow_hash`].
+///
+/// [`BTreeMap::get`]: std::collections::BTreeMap::get
+pub fn borrow_ord<K, Q>(a: &K, b: &K) -> Result<(), BorrowError>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    let mut report = Report::<BorrowError>::new();
+    report.check(laws::borrow_eq::<K, Q>(a, b));
+    report.check(laws::borrow_ord::<K, Q>(a, b));
+    report.into_result().map_err(Violation::into_error)
+}
+
+/// Checks that the [`hashbrown::Equivalent`] implementation of `Q` is
+/// coherent with the [`Hash`] and [`Eq`] implementations of `K`:
+///
+/// - if `q.equivalent(a)`, then `q` and `a` *MUST* write the same data to the
+///   hasher, and
+/// - if `q.equivalent(a)`, then `q.equivalent(b)` *MUST* be equal to `a ==
+///   b` (and vice versa).
+///
+/// A mismatch makes lookups by `&Q` silently miss entries, just like a broken
+/// [`Borrow`] implementation (see [`borrow_hash`]).
+#[cfg(feature = "hashbrown")]
+pub fn hashbrown_equivalent<Q, K>(q: &Q, a: &K, b: &K) -> Result<(), EquivalentError>
+where
+    Q: hashbrown::Equivalent<K> + Hash + ?Sized,
+    K: Hash + Eq + ?Sized,
+{
+    equivalent_checks(q, a, b, |q: &Q, k: &K| q.equivalent(k))
+}
+
+/// Like [`hashbrown_equivalent`], but for [`indexmap::Equivalent`].
+#[cfg(feature = "indexmap")]
+pub fn indexmap_equivalent<Q, K>(q: &Q, a: &K, b: &K) -> Result<(), EquivalentError>
+where
+    Q: indexmap::Equivalent<K> + Hash + ?Sized,
+    K: Hash + Eq + ?Sized,
+{
+    equivalent_checks(q, a, b, |q: &Q, k: &K| q.equivalent(k))
+}
+
+/// Checks that a comparator closure, e.g. one passed to [`slice::sort_by`],
+/// [`Iterator::max_by`], or [`slice::binary_search_by`], is a [total
+/// order](https://en.wikipedia.org/wiki/Total_order) for some values.
+///
+/// This checks the same invariants as [`ord`], but against `cmp` instead of
+/// the [`Ord`] implementation of `T` (if any).
+pub fn comparator<T, F>(a: &T, b: &T, c: &T, cmp: F) -> Result<(), Error>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let by = |value| adapters::By { value, cmp: &cmp };
+    ord(&by(a), &by(b), &by(c))
+}
+
+/// Checks that a partial comparator closure, i.e. one with the same signature
+/// as [`PartialOrd::partial_cmp`], is a partial order for some values.
+///
+/// This checks the same invariants as [`partial_ord`], but against
+/// `partial_cmp` instead of the [`PartialOrd`] implementation of `T` (if any).
+/// `a == b` is taken to mean `partial_cmp(a, b) == Some(Ordering::Equal)`.
+pub fn partial_comparator<T, F>(a: &T, b: &T, c: &T, partial_cmp: F) -> Result<(), Error>
+where
+    F: Fn(&T, &T) -> Option<Ordering>,
+{
+    let by = |value| adapters::PartialBy {
+        value,
+        partial_cmp: &partial_cmp,
+    };
+    partial_ord(&by(a), &by(b), &by(c))
+}
+
+/// Checks that a closure is an [equivalence
+/// relation](https://en.wikipedia.org/wiki/Equivalence_relation) for some
+/// values, e.g. one passed to [`Vec::dedup_by`].
+///
+/// This checks the same invariants as [`eq`], but against `eq` instead of the
+/// [`PartialEq`] implementation of `T` (if any).
+pub fn equivalence<T, F>(a: &T, b: &T, c: &T, eq: F) -> Result<(), Error>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let by = |value| adapters::EqBy { value, eq: &eq };
+    self::eq(&by(a), &by(b), &by(c))
+}
+
+/// Checks that the [`Ord`] implementation of `T` orders values exactly like
+/// their keys, i.e. that `a.cmp(b)` is equal to `key(a).cmp(&key(b))`.
+///
+/// This is useful for hand-written implementations which are meant to order
+/// by some projection of the values, e.g. `|task| (task.priority, task.id)`,
+/// as it checks them against their specification rather than just for
+/// internal consistency.
+pub fn ord_matches_key<T, K, F>(a: &T, b: &T, key: F) -> Result<(), OrdError>
+where
+    T: Ord,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    laws::ord_matches_key(a, b, key).map_err(Violation::into_error)
+}
+
+/// Checks that the [`PartialOrd`] implementation of `T` orders values exactly
+/// like their keys, i.e. that `a.partial_cmp(b)` is equal to
+/// `key(a).partial_cmp(&key(b))`.
+///
+/// See [`ord_matches_key`].
+pub fn partial_ord_matches_key<T, K, F>(a: &T, b: &T, key: F) -> Result<(), PartialOrdError>
+where
+    T: PartialOrd,
+    K: PartialOrd,
+    F: Fn(&T) -> K,
+{
+    laws::partial_ord_matches_key(a, b, key).map_err(Violation::into_error)
+}
+
+/// Checks that the [`PartialEq`] implementation of `T` compares values
+/// exactly like their keys, i.e. that `a == b` is equal to `key(a) ==
+/// key(b)`.
+///
+/// See [`ord_matches_key`].
+pub fn eq_matches_key<T, K, F>(a: &T, b: &T, key: F) -> Result<(), PartialEqError>
+where
+    T: PartialEq,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    laws::eq_matches_key(a, b, key).map_err(Violation::into_error)
+}
+
+/// Checks that the [`Ord`] implementation of `T` agrees with `old_cmp`, i.e.
+/// that `a.cmp(b)` is equal to `old_cmp(a, b)`.
+///
+/// This is useful when rewriting a comparison (e.g. for speed), as the old
+/// implementation can be kept around as an oracle in tests.
+pub fn ord_agrees_with<T, F>(a: &T, b: &T, old_cmp: F) -> Result<(), OrdError>
+where
+    T: Ord,
+    F: Fn(&T, &T) -> Ordering,
+{
+    laws::ord_agrees_with(a, b, old_cmp).map_err(Violation::into_error)
+}
+
+/// Checks that the [`PartialEq`] implementation of `T` agrees with `old_eq`,
+/// i.e. that `a == b` is equal to `old_eq(a, b)`.
+///
+/// See [`ord_agrees_with`].
+pub fn partial_eq_agrees_with<T, F>(a: &T, b: &T, old_eq: F) -> Result<(), PartialEqError>
+where
+    T: PartialEq,
+    F: Fn(&T, &T) -> bool,
+{
+    laws::partial_eq_agrees_with(a, b, old_eq).map_err(Violation::into_error)
+}
+
+/// Checks that the [`Hash`] implementation of `K` agrees with `old_hash`, i.e.
+/// that both write exactly the same data to the hasher.
+///
+/// Since [`Hash::hash`] is generic over the hasher, `old_hash` receives a
+/// `&mut dyn Hasher`; hash fields with e.g. `field.hash(&mut state)`.
+///
+/// See [`ord_agrees_with`].
+pub fn hash_agrees_with<K, F>(a: &K, old_hash: F) -> Result<(), HashError>
+where
+    K: Hash + ?Sized,
+    F: Fn(&K, &mut dyn Hasher),
+{
+    laws::hash_agrees_with(a, old_hash).map_err(Violation::into_error)
+}
+
+/// Checks that `f` is monotone (i.e. order-preserving) on `a` and `b`: if
+/// `a <= b`, then `f(a) <= f(b)` *MUST* be true.
+///
+/// This is useful for functions which map values to some other ordered type
+/// and are expected to preserve their order, e.g. timestamp encoders or
+/// normalized keys. `a` and `b` may be given in either order, as both `a <=
+/// b` and `b <= a` are considered; the converse implication (from `f(a) <=
+/// f(b)` to `a <= b`) is not checked.
+pub fn monotone<T, U, F>(f: F, a: &T, b: &T) -> Result<(), MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+    F: Fn(&T) -> U,
+{
+    laws::monotone(f, a, b).map_err(Violation::into_error)
+}
+
+/// Like [`monotone`], but returns a [`Violation`] which refers to `a`, `b`,
+/// `f(a)`, and `f(b)`.
+pub fn monotone_verbose<T, U, F>(f: F, a: &T, b: &T) -> Result<(), Violation<MonotonicityError>>
+where
+    T: PartialOrd + fmt::Debug,
+    U: PartialOrd + fmt::Debug,
+    F: Fn(&T) -> U,
+{
+    laws::monotone(&f, a, b).map_err(|v| with_images(v, f, a, b))
+}
+
+/// Checks that `f` is strictly monotone on `a` and `b`: if `a < b`, then
+/// `f(a) < f(b)` *MUST* be true.
+///
+/// See [`monotone`].
+pub fn strictly_monotone<T, U, F>(f: F, a: &T, b: &T) -> Result<(), MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+    F: Fn(&T) -> U,
+{
+    laws::strictly_monotone(f, a, b).map_err(Violation::into_error)
+}
+
+/// Like [`strictly_monotone`], but returns a [`Violation`] which refers to
+/// `a`, `b`, `f(a)`, and `f(b)`.
+pub fn strictly_monotone_verbose<T, U, F>(
+    f: F,
+    a: &T,
+    b: &T,
+) -> Result<(), Violation<MonotonicityError>>
+where
+    T: PartialOrd + fmt::Debug,
+    U: PartialOrd + fmt::Debug,
+    F: Fn(&T) -> U,
+{
+    laws::strictly_monotone(&f, a, b).map_err(|v| with_images(v, f, a, b))
+}
+
+/// Checks that `f` is antitone (i.e. order-reversing) on `a` and `b`: if `a <=
+/// b`, then `f(a) >= f(b)` *MUST* be true.
+///
+/// See [`monotone`].
+pub fn antitone<T, U, F>(f: F, a: &T, b: &T) -> Result<(), MonotonicityError>
+where
+    T: PartialOrd,
+    U: PartialOrd,
+    F: Fn(&T) -> U,
+{
+    laws::antitone(f, a, b).map_err(Violation::into_error)
+}
+
+/// Like [`antitone`], but returns a [`Violation`] which refers to `a`, `b`,
+/// `f(a)`, and `f(b)`.
+pub fn antitone_verbose<T, U, F>(f: F, a: &T, b: &T) -> Result<(), Violation<MonotonicityError>>
+where
+    T: PartialOrd + fmt::Debug,
+    U: PartialOrd + fmt::Debug,
+    F: Fn(&T) -> U,
+{
+    laws::antitone(&f, a, b).map_err(|v| with_images(v, f, a, b))
+}
+
+/// Checks that `encode` is an order-preserving byte encoding of `T`, as used
+/// for keys in sorted key-value stores: `encode(a).cmp(&encode(b))` *MUST* be
+/// equal to `a.cmp(b)`, and if `a != b`, neither of the two encodings can be
+/// a prefix of the other.
+///
+/// The latter guarantees that encodings can be concatenated (e.g. in
+/// composite keys) without breaking the order.
+pub fn ord_preserving_encoding<T, E, F>(a: &T, b: &T, encode: F) -> Result<(), EncodingError>
+where
+    T: Ord,
+    E: AsRef<[u8]>,
+    F: Fn(&T) -> E,
+{
+    laws::ord_preserving_encoding(a, b, encode).map_err(Violation::into_error)
+}
+
+/// Checks the correctness of the [`Ord`] trait (and [`Eq`] and [`PartialOrd`]
+/// by extension) across a whole sample of values.
+///
+/// Rather than checking all `O(n³)` triples of values, the sample is sorted
+/// and only the triples which are most likely to expose a broken invariant are
+/// checked, which takes `O(n log n)` comparisons. The returned [`Violation`]
+/// refers to the offending values and their indices within `sample`.
+pub fn ord_sample<T>(sample: &[T]) -> Result<(), Violation>
+where
+    T: Ord + fmt::Debug,
+{
+    sample::sortable(sample, |a, b| Some(a.cmp(b)), ord_checks)
+}
+
+/// Checks the correctness of the [`PartialOrd`] trait (and [`PartialEq`] by
+/// extension) across a whole sample of values.
+///
+/// See [`ord_sample`]. Incomparable values make the search for broken
+/// invariants less effective, so prefer [`ord_sample`] whenever possible.
+pub fn partial_ord_sample<T>(sample: &[T]) -> Result<(), Violation>
+where
+    T: PartialOrd + fmt::Debug,
+{
+    sample::sortable(sample, |a, b| a.partial_cmp(b), partial_ord_checks)
+}
+
+/// Checks that `<` is transitive across a whole sample of values by looking
+/// for cycles such as `a < b && b < c && c == d && d < a`.
+///
+/// Unlike [`partial_ord_sample`], which only checks chains of up to three
+/// values, this finds broken chains of any length, and returns the shortest
+/// one. However, it compares all pairs of values and takes `O(n³)` time, so
+/// it's only viable for samples of up to a few hundred values.
+pub fn partial_ord_cycles<T>(sample: &[T]) -> Result<(), Violation<PartialOrdError>>
+where
+    T: PartialOrd + fmt::Debug,
+{
+    sample::cycles(sample)
+}
+
+/// Checks the correctness of the [`Eq`] trait (and [`PartialEq`] by
+/// extension) across a whole sample of values.
+///
+/// Values are grouped by equality, which takes `O(n k)` comparisons where `k`
+/// is the number of distinct values in `sample`. The returned [`Violation`]
+/// refers to the offending values and their indices within `sample`.
+pub fn eq_sample<T>(sample: &[T]) -> Result<(), Violation>
+where
+    T: PartialEq + fmt::Debug,
+{
+    sample::groupable(sample, eq_checks)
+}
+
+/// Checks that `==` partitions a whole sample of values into [equivalence
+/// classes](https://en.wikipedia.org/wiki/Equivalence_class), i.e. that all
+/// values in each class are equal to each other and unequal to all values in
+/// other classes. This is what [`HashMap`](std::collections::HashMap) keys
+/// rely on.
+///
+/// Unlike [`eq_sample`], this compares all pairs of values, which takes
+/// `O(n²)` comparisons. If transitivity is broken, the returned [`Violation`]
+/// contains the chain of `==` which links the unequal pair of values with the
+/// smallest indices.
+pub fn eq_classes<T>(sample: &[T]) -> Result<(), Violation>
+where
+    T: PartialEq + fmt::Debug,
+{
+    sample::classes(sample)
+}
+
+/// Checks the correctness of the [`Hash`] trait in relation to [`Eq`] across
+/// a whole sample of values.
+///
+/// Values are grouped by equality, which takes `O(n k)` comparisons where `k`
+/// is the number of distinct values in `sample`, and all equal values must
+/// have the same hasher output. Values are then sorted and grouped by hasher
+/// output, and each group is only checked against a logarithmic number of
+/// other groups for collisions. The returned [`Violation`] refers to the
+/// offending values and their indices within `sample`.
+///
+/// Correctness bugs are always reported before quality issues (see
+/// [`HashError::is_quality_issue`]), so the latter can be ignored by
+/// inspecting the returned [`Violation`].
+pub fn hash_sample<K>(sample: &[K]) -> Result<(), Violation<HashError>>
+where
+    K: Hash + Eq + fmt::Debug,
+{
+    sample::hashable(sample)
+}
 
-    Ok(())
+/// Checks the correctness of the [`Clone`] trait in relation to [`Eq`] for
+/// some values: clones *MUST* be equal to their source, and
+/// [`Clone::clone_from`] *MUST* have the same result as [`Clone::clone`].
+///
+/// Hand-written [`Clone`] implementations which forget a field are caught by
+/// this, as long as [`Eq`] doesn't forget the same field.
+pub fn clone<T>(a: &T, b: &T) -> Result<(), CloneError>
+where
+    T: Clone + Eq,
+{
+    clone_checks(a, b)
+        .into_result()
+        .map_err(Violation::into_error)
+}
+
+/// Like [`clone`], but also checks that `a.clone().cmp(&a)` is
+/// [`Ordering::Equal`].
+pub fn clone_ord<T>(a: &T, b: &T) -> Result<(), CloneError>
+where
+    T: Clone + Ord,
+{
+    let mut report = clone_checks(a, b);
+    report.check(laws::clone_consistency_with_ord(a));
+    report.check(laws::clone_consistency_with_ord(b));
+    report.into_result().map_err(Violation::into_error)
+}
+
+/// Like [`clone`], but also checks that clones write the same data to the
+/// hasher as their source.
+pub fn clone_hash<T>(a: &T, b: &T) -> Result<(), CloneError>
+where
+    T: Clone + Eq + Hash,
+{
+    let mut report = clone_checks(a, b);
+    report.check(laws::clone_consistency_with_hash(a, b));
+    report.check(laws::clone_consistency_with_hash(b, a));
+    report.into_result().map_err(Violation::into_error)
 }
 
 /// Checks the correctness of the [`Iterator`] trait for some value `iter`.
@@ -240,11 +1019,21 @@ where
     I: Iterator + Clone,
     I::Item: PartialEq,
 {
-    invariants::iterator_size_hint(iter.clone())?;
-    invariants::iterator_count(iter.clone())?;
-    invariants::iterator_last(iter)?;
+    iterator_report(iter)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Like [`iterator`], but checks all invariants instead of stopping at the
+/// first broken one.
+pub fn iterator_report<I>(iter: I) -> Report<IteratorError>
+where
+    I: Iterator + Clone,
+    I::Item: PartialEq,
+{
+    let mut report = Report::new();
+    iterator_laws(iter, &mut report);
+    report
 }
 
 /// Checks the correctness of the [`DoubleEndedIterator`] trait (and
@@ -256,11 +1045,22 @@ where
     I: DoubleEndedIterator + Clone,
     I::Item: PartialEq,
 {
-    iterator(iter.clone())?;
-
-    invariants::double_ended_iterator_next_back(iter)?;
+    double_ended_iterator_report(iter)
+        .into_result()
+        .map_err(Violation::into_error)
+}
 
-    Ok(())
+/// Like [`double_ended_iterator`], but checks all invariants instead of
+/// stopping at the first broken one.
+pub fn double_ended_iterator_report<I>(iter: I) -> Report<IteratorError>
+where
+    I: DoubleEndedIterator + Clone,
+    I::Item: PartialEq,
+{
+    let mut report = Report::new();
+    iterator_laws(iter.clone(), &mut report);
+    report.check(laws::double_ended_iterator_next_back(iter));
+    report
 }
 
 /// Checks the correctness of the [`FusedIterator`] trait (and
@@ -272,11 +1072,187 @@ where
     I: FusedIterator + Clone,
     I::Item: PartialEq,
 {
-    iterator(iter.clone())?;
+    fused_iterator_report(iter)
+        .into_result()
+        .map_err(Violation::into_error)
+}
+
+/// Like [`fused_iterator`], but checks all invariants instead of stopping at
+/// the first broken one.
+pub fn fused_iterator_report<I>(iter: I) -> Report<IteratorError>
+where
+    I: FusedIterator + Clone,
+    I::Item: PartialEq,
+{
+    let mut report = Report::new();
+    iterator_laws(iter.clone(), &mut report);
+    report.check(laws::fused_iterator_none_forever(iter));
+    report
+}
+
+fn ord_checks<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: Ord,
+{
+    let mut report = Report::new();
+    partial_eq_laws(a, b, c, &mut report);
+    eq_laws(a, &mut report);
+    partial_ord_laws(a, b, c, &mut report);
+    ord_laws(a, b, &mut report);
+    report
+}
+
+fn partial_ord_checks<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: PartialOrd,
+{
+    let mut report = Report::new();
+    partial_eq_laws(a, b, c, &mut report);
+    partial_ord_laws(a, b, c, &mut report);
+    report
+}
+
+fn eq_checks<T>(a: &T, b: &T, c: &T) -> Report
+where
+    T: PartialEq<T>,
+{
+    let mut report = Report::new();
+    partial_eq_laws(a, b, c, &mut report);
+    eq_laws(a, &mut report);
+    report
+}
 
-    invariants::fused_iterator_none_forever(iter)?;
+fn partial_eq_checks<T>(a: &T, b: &T, c: &T) -> Report<PartialEqError>
+where
+    T: PartialEq,
+{
+    let mut report = Report::new();
+    partial_eq_laws(a, b, c, &mut report);
+    report
+}
 
-    Ok(())
+#[cfg(any(feature = "hashbrown", feature = "indexmap"))]
+fn equivalent_checks<Q, K>(
+    q: &Q,
+    a: &K,
+    b: &K,
+    equivalent: impl Fn(&Q, &K) -> bool,
+) -> Result<(), EquivalentError>
+where
+    Q: Hash + ?Sized,
+    K: Hash + Eq + ?Sized,
+{
+    let mut report = Report::<EquivalentError>::new();
+    report.check(laws::equivalent_hash(q, a, &equivalent));
+    report.check(laws::equivalent_hash(q, b, &equivalent));
+    report.check(laws::equivalent_eq(q, a, b, &equivalent));
+    report.check(laws::equivalent_eq(q, b, a, &equivalent));
+    report.into_result().map_err(Violation::into_error)
+}
+
+fn clone_checks<T>(a: &T, b: &T) -> Report<CloneError>
+where
+    T: Clone + Eq,
+{
+    let mut report = Report::new();
+    report.check(laws::clone_consistency_with_eq(a));
+    report.check(laws::clone_consistency_with_eq(b));
+    report.check(laws::clone_from_consistency(a, b));
+    report.check(laws::clone_from_consistency(b, a));
+    report
+}
+
+fn hash_checks<K>(a: &K, b: &K) -> Report<HashError>
+where
+    K: Hash + Eq + ?Sized,
+{
+    let mut report = Report::new();
+    report.check(laws::hash_purity(a, b));
+    report.check(laws::hash_consistency_with_eq(a, b));
+    report.check(laws::hash_prefix_collision(a, b));
+    report
+}
+
+// The `*_laws` functions only check the invariants that are specific to each
+// trait, e.g. `ord_laws` doesn't check `PartialOrd` invariants. This way each
+// invariant is reported at most once.
+
+fn ord_laws<T, E>(a: &T, b: &T, report: &mut Report<E>)
+where
+    T: Ord,
+    Violation<OrdError>: Into<Violation<E>>,
+{
+    report.check(laws::ord_purity(a, b));
+    report.check(laws::ord_methods_consistency(a, b));
+    report.check(laws::ord_cmp_antisymmetry(a, b));
+}
+
+fn partial_ord_laws<T, E>(a: &T, b: &T, c: &T, report: &mut Report<E>)
+where
+    T: PartialOrd,
+    Violation<PartialOrdError>: Into<Violation<E>>,
+{
+    report.check(laws::partial_ord_purity(a, b));
+    report.check(laws::partial_ord_methods_consistency(a, b));
+    report.check(laws::partial_ord_incomparability(a, b));
+    report.check(laws::partial_ord_irreflexivity(a));
+    report.check(laws::partial_ord_asymmetry(a, b));
+    report.check(laws::partial_ord_antisymmetry(a, b));
+    report.check(laws::partial_ord_duality(a, b));
+    report.check(laws::partial_ord_transitivity(a, b, c));
+}
+
+fn eq_laws<T, E>(a: &T, report: &mut Report<E>)
+where
+    T: PartialEq<T>,
+    Violation<EqError>: Into<Violation<E>>,
+{
+    // Checking `Eq` is the same as checking `PartialEq`, except it also
+    // requires reflexivity.
+    report.check(laws::eq_reflexivity(a));
+}
+
+fn partial_eq_laws<T, E>(a: &T, b: &T, c: &T, report: &mut Report<E>)
+where
+    T: PartialEq,
+    Violation<PartialEqError>: Into<Violation<E>>,
+{
+    report.check(laws::partial_eq_purity(a, b));
+    report.check(laws::partial_eq_methods_consistency(a, b));
+    report.check(laws::partial_eq_symmetry(a, b));
+    report.check(laws::partial_eq_transitivity(a, b, c));
+}
+
+fn iterator_laws<I>(iter: I, report: &mut Report<IteratorError>)
+where
+    I: Iterator + Clone,
+    I::Item: PartialEq,
+{
+    report.check(laws::iterator_size_hint(iter.clone()));
+    report.check(laws::iterator_count(iter.clone()));
+    report.check(laws::iterator_last(iter));
+}
+
+fn with_values<E, T>(violation: Violation<E>, a: &T, b: &T, c: &T) -> Violation<E>
+where
+    T: fmt::Debug,
+{
+    violation
+        .with_value("a", a)
+        .with_value("b", b)
+        .with_value("c", c)
+}
+
+fn with_images<E, T, U>(violation: Violation<E>, f: impl Fn(&T) -> U, a: &T, b: &T) -> Violation<E>
+where
+    T: fmt::Debug,
+    U: fmt::Debug,
+{
+    violation
+        .with_value("a", a)
+        .with_value("b", b)
+        .with_value("f(a)", &f(a))
+        .with_value("f(b)", &f(b))
 }
 
 #[allow(dead_code)]
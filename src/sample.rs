@@ -0,0 +1,437 @@
+//! Algorithms for checking invariants across whole samples of values.
+//!
+//! Checking all triples of a sample is `O(n³)`, which quickly becomes
+//! impractical. Instead, we sort or group the sample and only check the
+//! triples that are most likely to expose a broken invariant. Every
+//! [`Violation`] returned by these functions is confirmed on concrete values,
+//! so the heuristics can only cause false negatives, never false positives.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+use crate::{error::*, laws};
+
+/// Checks a sample of values which can be sorted by `cmp`.
+///
+/// After sorting, neighbouring values are checked against each other and every
+/// value is checked against values at exponentially increasing distances
+/// (`1, 2, 4, ...`) ahead of it, which amounts to `O(n log n)` checks. If a
+/// value compares greater than a value after it, there must be a broken
+/// transitivity chain between the two, which we look for.
+///
+/// Incomparable values are treated as equal for the purpose of sorting, which
+/// makes the search less effective for partial orders. Values which are not
+/// even comparable to themselves (e.g. `NaN`) are left out of sorting
+/// altogether.
+pub fn sortable<T, E>(
+    sample: &[T],
+    cmp: impl Fn(&T, &T) -> Option<Ordering>,
+    checks: impl Fn(&T, &T, &T) -> Report<E>,
+) -> Result<(), Violation<E>>
+where
+    T: PartialOrd + fmt::Debug,
+{
+    let check = |i: usize, j: usize, k: usize| {
+        checks(&sample[i], &sample[j], &sample[k])
+            .into_result()
+            .map_err(|v| with_indices(v, sample, [i, j, k]))
+    };
+    let greater = |i: usize, j: usize| cmp(&sample[i], &sample[j]) == Some(Ordering::Greater);
+
+    let mut comparable = vec![];
+    for (i, item) in sample.iter().enumerate() {
+        check(i, i, i)?;
+        if cmp(item, item).is_some() {
+            comparable.push(i);
+        } else if let Some(&first) = comparable.first() {
+            check(i, first, first)?;
+            check(first, i, i)?;
+        }
+    }
+
+    let sorted = merge_sort(comparable, &mut |i, j| !greater(i, j));
+
+    for p in 0..sorted.len().saturating_sub(1) {
+        let (prev, a, b) = (sorted[p.saturating_sub(1)], sorted[p], sorted[p + 1]);
+        let next = sorted.get(p + 2).copied().unwrap_or(b);
+        check(a, b, next)?;
+        check(b, a, prev)?;
+    }
+
+    for p in 0..sorted.len() {
+        let mut distance = 2;
+        while p + distance < sorted.len() {
+            if greater(sorted[p], sorted[p + distance]) {
+                // `sorted[p]` sorts before `sorted[p + distance]` but compares
+                // greater: the first value after `sorted[p]` which compares
+                // smaller than it closes the broken chain.
+                if let Some(k) = (p + 1..=p + distance).find(|&k| greater(sorted[p], sorted[k])) {
+                    let (i, j, k) = (sorted[p], sorted[k - 1], sorted[k]);
+                    check(i, j, k)?;
+                }
+            }
+            distance *= 2;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a sample of values which can only be compared for equality.
+///
+/// Values are grouped by comparing each of them against the first and the
+/// latest member of every group, which takes `O(n k)` comparisons where `k` is
+/// the number of groups. A value which is equal to only one of the two, or
+/// equal to members of two different groups, breaks transitivity.
+pub fn groupable<T, E>(
+    sample: &[T],
+    checks: impl Fn(&T, &T, &T) -> Report<E>,
+) -> Result<(), Violation<E>>
+where
+    T: PartialEq + fmt::Debug,
+{
+    eq_groups(sample, checks).map(drop)
+}
+
+/// Groups a sample by `==` as described in [`groupable`], and returns the
+/// indices of the members of each group.
+fn eq_groups<T, E>(
+    sample: &[T],
+    checks: impl Fn(&T, &T, &T) -> Report<E>,
+) -> Result<Vec<Vec<usize>>, Violation<E>>
+where
+    T: PartialEq + fmt::Debug,
+{
+    let check = |i: usize, j: usize, k: usize| {
+        checks(&sample[i], &sample[j], &sample[k])
+            .into_result()
+            .map_err(|v| with_indices(v, sample, [i, j, k]))
+    };
+
+    let mut groups: Vec<Vec<usize>> = vec![];
+    for i in 0..sample.len() {
+        check(i, i, i)?;
+
+        let mut group = None;
+        for (g, members) in groups.iter().enumerate() {
+            let (first, latest) = (members[0], members[members.len() - 1]);
+            check(i, first, latest)?;
+            check(i, latest, first)?;
+
+            let (eq_first, eq_latest) = (sample[i] == sample[first], sample[i] == sample[latest]);
+            if eq_first && !eq_latest {
+                check(latest, first, i)?;
+            } else if !eq_first && eq_latest {
+                check(first, latest, i)?;
+            } else if eq_first {
+                match group {
+                    None => group = Some(g),
+                    Some(other) => check(groups[other][0], i, first)?,
+                }
+            }
+        }
+
+        match group {
+            Some(g) => groups[g].push(i),
+            None => groups.push(vec![i]),
+        }
+    }
+
+    Ok(groups)
+}
+
+/// Partitions a sample of values into the equivalence classes of `==` and
+/// checks that all values within each class are equal to each other.
+///
+/// Classes are built with a union-find over all pairs of equal values, so
+/// values in different classes are unequal by construction. Any unequal pair
+/// within a class is linked by a chain of `==` which breaks transitivity; the
+/// pair with the smallest indices is reported together with its shortest
+/// chain. This takes `O(n²)` comparisons.
+pub fn classes<T>(sample: &[T]) -> Result<(), Violation>
+where
+    T: PartialEq + fmt::Debug,
+{
+    let n = sample.len();
+    let eq = sample
+        .iter()
+        .map(|x| sample.iter().map(|y| x == y).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let mut classes = UnionFind::new(n);
+    for i in 0..n {
+        laws::eq_reflexivity(&sample[i]).map_err(|v| with_indices(v, sample, [i, i, i]))?;
+        for j in i + 1..n {
+            laws::partial_eq_symmetry(&sample[i], &sample[j])
+                .map_err(|v| with_indices(v, sample, [i, j, j]))?;
+            if eq[i][j] {
+                classes.union(i, j);
+            }
+        }
+    }
+
+    let contradiction = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+        .find(|&(i, j)| !eq[i][j] && classes.find(i) == classes.find(j));
+    let (start, end) = match contradiction {
+        Some(pair) => pair,
+        None => return Ok(()),
+    };
+
+    // Breadth-first search for the shortest chain of `==` from `start` to `end`.
+    let mut parents = vec![None; n];
+    let mut queue = VecDeque::from(vec![start]);
+    parents[start] = Some(start);
+    while let Some(node) = queue.pop_front() {
+        for next in (0..n).filter(|&next| eq[node][next]) {
+            if parents[next].is_none() {
+                parents[next] = Some(node);
+                queue.push_back(next);
+            }
+        }
+    }
+    let mut chain = vec![end];
+    while chain[chain.len() - 1] != start {
+        chain.push(parents[chain[chain.len() - 1]].unwrap());
+    }
+    chain.reverse();
+
+    let mut violation = Violation::new(PartialEqError::BrokeTransitivity);
+    for (i, pair) in chain.windows(2).enumerate() {
+        let expr = format!("{} == {}", value_name(i), value_name(i + 1));
+        violation = violation.observe(expr, eq[pair[0]][pair[1]]);
+    }
+    let expr = format!("{} == {}", value_name(0), value_name(chain.len() - 1));
+    violation = violation.observe(expr, false);
+    for (i, &node) in chain.iter().enumerate() {
+        violation = violation.with_value(value_name(i), &sample[node]);
+    }
+
+    Err(violation.observe("sample indices", chain).into())
+}
+
+/// Checks a sample of hashable values.
+///
+/// Values are grouped by their hasher output, and each group is further split
+/// into classes of equal values. The first members of all classes are then
+/// grouped by `==` as in [`groupable`], which takes `O(n k)` comparisons where
+/// `k` is the number of classes: equal values with different hasher outputs
+/// end up in the same group.
+///
+/// Sorting the hasher outputs puts every output right before the outputs it's
+/// a prefix of, so prefix collisions always show up between neighbouring
+/// groups. The first value of every group is checked against those of the
+/// groups at exponentially increasing distances (`1, 2, 4, ...`) after it.
+/// Unequal values within the same group are only a quality issue, so they're
+/// reported last.
+pub fn hashable<K>(sample: &[K]) -> Result<(), Violation<HashError>>
+where
+    K: Hash + Eq + fmt::Debug,
+{
+    let check = |i: usize, j: usize| {
+        crate::hash_checks(&sample[i], &sample[j])
+            .into_result()
+            .map_err(|v| {
+                v.with_value("a", &sample[i])
+                    .with_value("b", &sample[j])
+                    .observe("sample indices of (a, b)", (i, j))
+            })
+    };
+
+    let mut groups = BTreeMap::<Vec<u8>, Vec<usize>>::new();
+    for (i, item) in sample.iter().enumerate() {
+        groups.entry(laws::hasher_output(item)).or_default().push(i);
+    }
+
+    // Splits each group into classes of equal values, whose first members
+    // stand for the whole class from here on.
+    let mut classes = Vec::<Vec<usize>>::new();
+    for group in groups.values() {
+        let mut firsts = Vec::<usize>::new();
+        for &i in group {
+            if !firsts.iter().any(|&first| sample[first] == sample[i]) {
+                firsts.push(i);
+            }
+        }
+        classes.push(firsts);
+    }
+
+    // Equal values must be in the same group, i.e. classes of different
+    // groups must be unequal.
+    let firsts = classes.iter().flatten().copied().collect::<Vec<_>>();
+    let values = firsts.iter().map(|&i| &sample[i]).collect::<Vec<_>>();
+    for members in eq_groups(&values, |_, _, _| Report::<HashError>::new())? {
+        if let [a, b, ..] = members[..] {
+            check(firsts[a], firsts[b])?;
+        }
+    }
+
+    let firsts = classes.iter().map(|class| class[0]).collect::<Vec<_>>();
+    for p in 0..firsts.len() {
+        let mut distance = 1;
+        while p + distance < firsts.len() {
+            check(firsts[p], firsts[p + distance])?;
+            distance *= 2;
+        }
+    }
+    for class in &classes {
+        if let [i, j, ..] = class[..] {
+            check(i, j)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Looks for the shortest cycle in the graph of `<` and `==` over a sample of
+/// values, which can't exist in a transitive relation unless it's made of
+/// `==` alone.
+///
+/// Building the graph takes `O(n²)` comparisons and searching it takes
+/// `O(n³)` time, so this is only viable for samples of up to a few hundred
+/// values.
+pub fn cycles<T>(sample: &[T]) -> Result<(), Violation<PartialOrdError>>
+where
+    T: PartialOrd + fmt::Debug,
+{
+    let n = sample.len();
+    let lt = sample
+        .iter()
+        .map(|x| sample.iter().map(|y| x < y).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let edges = sample
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            (0..n)
+                .filter(|&j| lt[i][j] || (i != j && *x == sample[j]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // The shortest cycle which contains at least one `<` edge, as the list of
+    // its nodes. It starts with the `<` edge.
+    let mut shortest: Option<Vec<usize>> = None;
+    for start in 0..n {
+        // Breadth-first search from `start`, then close the cycle through any
+        // `<` edge which leads back to `start`.
+        let mut parents = vec![None; n];
+        let mut queue = VecDeque::from(vec![start]);
+        parents[start] = Some(start);
+        while let Some(node) = queue.pop_front() {
+            for &next in &edges[node] {
+                if parents[next].is_none() {
+                    parents[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        for end in (0..n).filter(|&end| lt[end][start] && parents[end].is_some()) {
+            let mut path = vec![end];
+            while path[path.len() - 1] != start {
+                path.push(parents[path[path.len() - 1]].unwrap());
+            }
+            path.reverse();
+            // `end < start`, so the cycle starts at `end`.
+            path.rotate_right(1);
+
+            if shortest.as_ref().map_or(true, |s| path.len() < s.len()) {
+                shortest = Some(path);
+            }
+        }
+    }
+
+    let cycle = match shortest {
+        Some(cycle) => cycle,
+        None => return Ok(()),
+    };
+    let mut violation = Violation::new(PartialOrdError::Cycle);
+    for (i, &node) in cycle.iter().enumerate() {
+        let next = cycle[(i + 1) % cycle.len()];
+        let (x, y) = (value_name(i), value_name((i + 1) % cycle.len()));
+        violation = if lt[node][next] {
+            violation.observe(format!("{} < {}", x, y), true)
+        } else {
+            violation.observe(format!("{} == {}", x, y), true)
+        };
+    }
+    for (i, &node) in cycle.iter().enumerate() {
+        violation = violation.with_value(value_name(i), &sample[node]);
+    }
+
+    Err(violation.observe("sample indices", cycle))
+}
+
+/// Names values `a` to `z`, then `x26`, `x27`, and so on.
+fn value_name(i: usize) -> String {
+    if i < 26 {
+        ((b'a' + i as u8) as char).to_string()
+    } else {
+        format!("x{}", i)
+    }
+}
+
+fn with_indices<T, E>(violation: Violation<E>, sample: &[T], indices: [usize; 3]) -> Violation<E>
+where
+    T: fmt::Debug,
+{
+    let [i, j, k] = indices;
+    crate::with_values(violation, &sample[i], &sample[j], &sample[k])
+        .observe("sample indices of (a, b, c)", (i, j, k))
+}
+
+/// A stable merge sort that, unlike [`slice::sort_by`], never panics nor
+/// loops forever if `le` is not a total order.
+fn merge_sort(mut indices: Vec<usize>, le: &mut impl FnMut(usize, usize) -> bool) -> Vec<usize> {
+    if indices.len() <= 1 {
+        return indices;
+    }
+
+    let right = merge_sort(indices.split_off(indices.len() / 2), le);
+    let left = merge_sort(indices, le);
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut l, mut r) = (0, 0);
+    while l < left.len() && r < right.len() {
+        if le(left[l], right[r]) {
+            merged.push(left[l]);
+            l += 1;
+        } else {
+            merged.push(right[r]);
+            r += 1;
+        }
+    }
+    merged.extend_from_slice(&left[l..]);
+    merged.extend_from_slice(&right[r..]);
+    merged
+}
+
+struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    fn union(&mut self, i: usize, j: usize) {
+        let (i, j) = (self.find(i), self.find(j));
+        self.parents[i] = j;
+    }
+}
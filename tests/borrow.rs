@@ -0,0 +1,86 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use quickcheck_macros::quickcheck;
+use reltester::error::BorrowError;
+
+#[quickcheck]
+fn borrow_string(a: String, b: String) -> bool {
+    reltester::borrow_hash::<String, str>(&a, &b).is_ok()
+        && reltester::borrow_ord::<String, str>(&a, &b).is_ok()
+}
+
+#[quickcheck]
+fn borrow_vec(a: Vec<u8>, b: Vec<u8>) -> bool {
+    reltester::borrow_hash::<Vec<u8>, [u8]>(&a, &b).is_ok()
+        && reltester::borrow_ord::<Vec<u8>, [u8]>(&a, &b).is_ok()
+}
+
+/// A case-insensitive key which (incorrectly) borrows as the original string.
+#[derive(Debug)]
+struct Key(String);
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .to_ascii_lowercase()
+            .cmp(&other.0.to_ascii_lowercase())
+    }
+}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_ascii_lowercase().hash(state);
+    }
+}
+
+impl Borrow<str> for Key {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+#[test]
+fn borrow_case_insensitive_key_is_incorrect() {
+    let (a, b) = (Key("Foo".to_string()), Key("foo".to_string()));
+
+    assert!(matches!(
+        reltester::borrow_hash::<Key, str>(&a, &b),
+        Err(BorrowError::BadEq)
+    ));
+    assert!(matches!(
+        reltester::borrow_ord::<Key, str>(&a, &b),
+        Err(BorrowError::BadEq)
+    ));
+}
+
+#[test]
+fn borrow_case_insensitive_key_breaks_hash_and_ord() {
+    let (a, b) = (Key("B".to_string()), Key("a".to_string()));
+
+    assert!(matches!(
+        reltester::borrow_hash::<Key, str>(&a, &b),
+        Err(BorrowError::BadHash)
+    ));
+    assert!(matches!(
+        reltester::borrow_ord::<Key, str>(&a, &b),
+        Err(BorrowError::BadCmp)
+    ));
+}
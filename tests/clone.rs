@@ -0,0 +1,114 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+};
+
+use quickcheck_macros::quickcheck;
+use reltester::error::CloneError;
+
+#[quickcheck]
+fn clone_strings(a: String, b: String) -> bool {
+    reltester::clone(&a, &b).is_ok()
+        && reltester::clone_ord(&a, &b).is_ok()
+        && reltester::clone_hash(&a, &b).is_ok()
+}
+
+#[quickcheck]
+fn clone_maps(a: BTreeMap<u8, Vec<u8>>, b: BTreeMap<u8, Vec<u8>>) -> bool {
+    reltester::clone_ord(&a, &b).is_ok() && reltester::clone_hash(&a, &b).is_ok()
+}
+
+/// A node in an arena, whose hand-written `Clone` implementation forgets
+/// the parent.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Node {
+    id: u32,
+    parent: Option<u32>,
+}
+
+impl Clone for Node {
+    fn clone(&self) -> Self {
+        Node {
+            id: self.id,
+            parent: None,
+        }
+    }
+}
+
+#[test]
+fn clone_forgotten_field_is_incorrect() {
+    let (a, b) = (
+        Node {
+            id: 1,
+            parent: Some(0),
+        },
+        Node {
+            id: 2,
+            parent: None,
+        },
+    );
+
+    assert!(matches!(
+        reltester::clone(&a, &b),
+        Err(CloneError::NotEqual)
+    ));
+    assert!(matches!(
+        reltester::invariants::clone_consistency_with_ord(&a),
+        Err(CloneError::CmpNotEqual)
+    ));
+    assert!(matches!(
+        reltester::invariants::clone_consistency_with_hash(&a, &b),
+        Err(CloneError::DifferentHashes)
+    ));
+}
+
+/// A buffer whose `clone_from` reuses the allocation but forgets to copy the
+/// length.
+#[derive(Debug, PartialEq, Eq)]
+struct Buffer(Vec<u8>);
+
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        Buffer(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        for (x, y) in self.0.iter_mut().zip(&source.0) {
+            *x = *y;
+        }
+    }
+}
+
+impl PartialOrd for Buffer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Buffer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Hash for Buffer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn clone_from_is_incorrect() {
+    let (a, b) = (Buffer(vec![1, 2, 3]), Buffer(vec![4]));
+
+    assert!(reltester::invariants::clone_consistency_with_eq(&a).is_ok());
+    assert!(matches!(
+        reltester::clone_ord(&a, &b),
+        Err(CloneError::BadCloneFrom)
+    ));
+    assert!(matches!(
+        reltester::invariants::clone_consistency_with_hash(&a, &b),
+        Err(CloneError::DifferentHashes)
+    ));
+}
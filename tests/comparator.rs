@@ -0,0 +1,43 @@
+use std::cmp::Ordering;
+
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn comparator_by_key(a: (u8, String), b: (u8, String), c: (u8, String)) -> bool {
+    reltester::comparator(&a, &b, &c, |x, y| x.0.cmp(&y.0).then(y.1.cmp(&x.1))).is_ok()
+}
+
+#[quickcheck]
+fn partial_comparator_f32(a: f32, b: f32, c: f32) -> bool {
+    reltester::partial_comparator(&a, &b, &c, |x, y| x.partial_cmp(y)).is_ok()
+}
+
+#[quickcheck]
+fn equivalence_case_insensitive(a: String, b: String, c: String) -> bool {
+    reltester::equivalence(&a, &b, &c, |x, y| x.eq_ignore_ascii_case(y)).is_ok()
+}
+
+#[test]
+fn comparator_unwrap_or_equal_is_not_total() {
+    let cmp = |x: &f32, y: &f32| x.partial_cmp(y).unwrap_or(Ordering::Equal);
+    assert!(reltester::comparator(&1.0, &f32::NAN, &2.0, cmp).is_err());
+}
+
+#[test]
+fn comparator_not_antisymmetric() {
+    // A common mistake: `<=` instead of `<`.
+    let cmp = |x: &u32, y: &u32| {
+        if x <= y {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    };
+    assert!(reltester::comparator(&1, &1, &2, cmp).is_err());
+}
+
+#[test]
+fn equivalence_epsilon_is_not_transitive() {
+    let eq = |x: &f64, y: &f64| (x - y).abs() < 0.1;
+    assert!(reltester::equivalence(&0.0, &0.06, &0.12, eq).is_err());
+}
@@ -0,0 +1,43 @@
+use quickcheck_macros::quickcheck;
+use reltester::error::EncodingError;
+
+/// Big-endian integers followed by zero-escaped, zero-terminated strings.
+fn encode(key: &(u32, String)) -> Vec<u8> {
+    let mut bytes = key.0.to_be_bytes().to_vec();
+    for byte in key.1.bytes() {
+        bytes.push(byte);
+        if byte == 0 {
+            bytes.push(0xff);
+        }
+    }
+    bytes.extend_from_slice(&[0, 0]);
+    bytes
+}
+
+#[quickcheck]
+fn encoding_composite_keys(a: (u32, String), b: (u32, String)) -> bool {
+    reltester::ord_preserving_encoding(&a, &b, encode).is_ok()
+}
+
+#[quickcheck]
+fn encoding_big_endian(a: u64, b: u64) -> bool {
+    reltester::ord_preserving_encoding(&a, &b, |x| x.to_be_bytes()).is_ok()
+}
+
+#[test]
+fn encoding_little_endian_is_out_of_order() {
+    assert!(matches!(
+        reltester::ord_preserving_encoding(&1u32, &256u32, |x| x.to_le_bytes()),
+        Err(EncodingError::BadOrder)
+    ));
+}
+
+#[test]
+fn encoding_raw_strings_are_not_prefix_free() {
+    let (a, b) = ("foo".to_string(), "foobar".to_string());
+
+    assert!(matches!(
+        reltester::ord_preserving_encoding(&a, &b, |x| x.as_bytes().to_vec()),
+        Err(EncodingError::PrefixCollision)
+    ));
+}
@@ -0,0 +1,125 @@
+#![cfg(any(feature = "hashbrown", feature = "indexmap"))]
+
+use std::hash::{Hash, Hasher};
+
+use quickcheck_macros::quickcheck;
+use reltester::error::EquivalentError;
+
+#[cfg(feature = "hashbrown")]
+#[quickcheck]
+fn hashbrown_equivalent_str_for_string(q: String, a: String, b: String) -> bool {
+    reltester::hashbrown_equivalent::<str, String>(&q, &a, &b).is_ok()
+        && reltester::hashbrown_equivalent::<str, String>(&a, &a, &b).is_ok()
+}
+
+#[cfg(feature = "indexmap")]
+#[quickcheck]
+fn indexmap_equivalent_str_for_string(q: String, a: String, b: String) -> bool {
+    reltester::indexmap_equivalent::<str, String>(&q, &a, &a).is_ok()
+        && reltester::indexmap_equivalent::<str, String>(&a, &a, &b).is_ok()
+}
+
+/// Only hashes the last name, which collides more often but is correct.
+#[derive(Debug, PartialEq, Eq)]
+struct Person {
+    first: String,
+    last: String,
+}
+
+impl Hash for Person {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.last.hash(state);
+    }
+}
+
+/// A borrowed lookup key for [`Person`].
+#[cfg(feature = "hashbrown")]
+struct PersonRef<'a>(&'a str, &'a str);
+
+#[cfg(feature = "hashbrown")]
+impl Hash for PersonRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.1.hash(state);
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl hashbrown::Equivalent<Person> for PersonRef<'_> {
+    fn equivalent(&self, key: &Person) -> bool {
+        self.0 == key.first && self.1 == key.last
+    }
+}
+
+/// A lookup key for [`Person`] which only compares the last name.
+#[derive(Hash)]
+struct LastName<'a>(&'a str);
+
+#[cfg(feature = "hashbrown")]
+impl hashbrown::Equivalent<Person> for LastName<'_> {
+    fn equivalent(&self, key: &Person) -> bool {
+        self.0 == key.last
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl indexmap::Equivalent<Person> for LastName<'_> {
+    fn equivalent(&self, key: &Person) -> bool {
+        self.0 == key.last
+    }
+}
+
+/// A lookup key for [`Person`] which hashes the first name.
+#[cfg(feature = "indexmap")]
+#[derive(Hash)]
+struct FirstName<'a>(&'a str);
+
+#[cfg(feature = "indexmap")]
+impl indexmap::Equivalent<Person> for FirstName<'_> {
+    fn equivalent(&self, key: &Person) -> bool {
+        self.0 == key.first
+    }
+}
+
+fn person(first: &str, last: &str) -> Person {
+    Person {
+        first: first.to_string(),
+        last: last.to_string(),
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn equivalent_person_ref_is_correct() {
+    let (a, b) = (person("Ada", "Lovelace"), person("Byron", "Lovelace"));
+
+    assert!(reltester::hashbrown_equivalent(&PersonRef("Ada", "Lovelace"), &a, &b).is_ok());
+    assert!(reltester::hashbrown_equivalent(&PersonRef("Ada", "Lovelace"), &a, &a).is_ok());
+    assert!(reltester::hashbrown_equivalent(&PersonRef("Alan", "Turing"), &a, &b).is_ok());
+}
+
+#[test]
+fn equivalent_last_name_is_incorrect() {
+    let (a, b) = (person("Ada", "Lovelace"), person("Byron", "Lovelace"));
+
+    #[cfg(feature = "hashbrown")]
+    assert!(matches!(
+        reltester::hashbrown_equivalent(&LastName("Lovelace"), &a, &b),
+        Err(EquivalentError::BadEq)
+    ));
+    #[cfg(feature = "indexmap")]
+    assert!(matches!(
+        reltester::indexmap_equivalent(&LastName("Lovelace"), &a, &b),
+        Err(EquivalentError::BadEq)
+    ));
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn equivalent_first_name_is_incorrect() {
+    let (a, b) = (person("Ada", "Lovelace"), person("Alan", "Turing"));
+
+    assert!(matches!(
+        reltester::indexmap_equivalent(&FirstName("Ada"), &a, &b),
+        Err(EquivalentError::BadHash)
+    ));
+}
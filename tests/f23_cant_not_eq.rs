@@ -1,4 +1,10 @@
+use reltester::error::PartialEqError;
+
 #[test]
 fn f64_not_eq() {
     assert!(reltester::invariants::eq_reflexivity(&f64::NAN).is_err());
+    assert!(matches!(
+        reltester::invariants::eq_reflexivity(&f64::NAN),
+        Err(PartialEqError::BrokeReflexivity)
+    ));
 }
@@ -1,6 +1,13 @@
-use std::{collections::BTreeSet, marker::PhantomData, path::PathBuf, rc::Rc};
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    path::PathBuf,
+    rc::Rc,
+};
 
 use quickcheck_macros::quickcheck;
+use reltester::error::HashError;
 
 #[quickcheck]
 fn hash_string(x1: String, x2: String) -> bool {
@@ -47,3 +54,211 @@ fn hash_rc() {
     let _rc2_cloned = rc2.clone();
     assert!(reltester::hash(&rc1, &rc2).is_ok());
 }
+
+/// Only hashes the integer part, which is correct but collides a lot.
+#[derive(Debug, PartialEq, Eq)]
+struct Fixed(i32, u32);
+
+impl Hash for Fixed {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn hash_same_stream_for_unequal_values_is_quality_issue() {
+    let (a, b) = (Fixed(1, 0), Fixed(1, 5));
+    let error = reltester::hash(&a, &b).unwrap_err();
+
+    assert!(matches!(error, HashError::UnequalButSameHashes));
+    assert!(error.is_quality_issue());
+
+    let report = reltester::hash_report(&a, &b).downgrade(HashError::is_quality_issue);
+    assert!(report.is_ok());
+    assert_eq!(report.warnings().len(), 1);
+}
+
+/// Compares by the second field but hashes the first one, which is both
+/// incorrect and collides a lot.
+#[derive(Debug)]
+struct Sloppy(u32, u32);
+
+impl PartialEq for Sloppy {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl Eq for Sloppy {}
+
+impl Hash for Sloppy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn hash_different_streams_for_equal_values_is_not_quality_issue() {
+    let error = reltester::hash(&Sloppy(0, 1), &Sloppy(1, 1)).unwrap_err();
+
+    assert!(matches!(error, HashError::EqualButDifferentHashes));
+    assert!(!error.is_quality_issue());
+}
+
+#[test]
+fn hash_sample_reports_correctness_bugs_first() {
+    let sample = [Sloppy(0, 1), Sloppy(0, 2), Sloppy(1, 1)];
+    let violation = reltester::hash_sample(&sample).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        HashError::EqualButDifferentHashes
+    ));
+}
+
+#[quickcheck]
+fn hash_concatenation_strings(a: Vec<String>, b: Vec<String>) -> bool {
+    reltester::hash_concatenation(&a, &b).is_ok()
+}
+
+#[quickcheck]
+fn hash_pair_concatenation_strings(a: (String, String), b: (String, String)) -> bool {
+    reltester::invariants::hash_pair_concatenation((&a.0, &a.1), (&b.0, &b.1)).is_ok()
+}
+
+/// Hashes its bytes without a terminator, so that concatenations are ambiguous.
+#[derive(Debug, PartialEq, Eq)]
+struct Unterminated(&'static str);
+
+impl Hash for Unterminated {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.0.as_bytes());
+    }
+}
+
+#[test]
+fn hash_concatenation_unterminated_is_incorrect() {
+    let (ab, c) = (Unterminated("ab"), Unterminated("c"));
+    let (a, bc) = (Unterminated("a"), Unterminated("bc"));
+
+    assert!(reltester::hash(&ab, &a).is_err());
+    assert!(matches!(
+        reltester::invariants::hash_pair_concatenation((&ab, &c), (&a, &bc)),
+        Err(HashError::ConcatenationCollision)
+    ));
+    assert!(matches!(
+        reltester::hash_concatenation(&[ab, c], &[a, bc]),
+        Err(HashError::ConcatenationCollision)
+    ));
+}
+
+#[test]
+fn hash_concatenation_equal_pairs_is_ok() {
+    let (a, b) = (Unterminated("a"), Unterminated("b"));
+    assert!(reltester::invariants::hash_pair_concatenation((&a, &b), (&a, &b)).is_ok());
+}
+
+#[quickcheck]
+fn hash_slice_integers(a: u32, b: u32, c: u32) -> bool {
+    reltester::hash_slice(&a, &b, &c).is_ok()
+}
+
+#[quickcheck]
+fn hash_slice_strings(a: String, b: String, c: String) -> bool {
+    reltester::hash_slice(&a, &b, &c).is_ok()
+}
+
+/// Hashes slices by their length only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Lazy(u32);
+
+impl Hash for Lazy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        data.len().hash(state);
+    }
+}
+
+/// Hashes slices by the field which isn't compared, which is inconsistent
+/// with equality.
+#[derive(Debug, Clone)]
+struct Broken(u32, u32);
+
+impl PartialEq for Broken {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Broken {}
+
+impl Hash for Broken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        for x in data {
+            x.1.hash(state);
+        }
+    }
+}
+
+#[test]
+fn hash_slice_override_is_only_a_quality_issue() {
+    let (a, b, c) = (Lazy(1), Lazy(2), Lazy(3));
+    let error = reltester::hash_slice(&a, &b, &c).unwrap_err();
+
+    assert!(matches!(error, HashError::HashSliceUnequalButSameHashes));
+    assert!(error.is_quality_issue());
+    assert!(matches!(
+        reltester::invariants::hash_slice_elementwise(&a, &b, &c),
+        Err(HashError::HashSliceDiffersFromElements)
+    ));
+
+    let report = reltester::hash_slice_report(&a, &b, &c).downgrade(HashError::is_quality_issue);
+    assert!(report.is_ok());
+    assert_eq!(report.warnings().len(), 2);
+}
+
+#[test]
+fn hash_slice_override_is_inconsistent_with_eq() {
+    let error = reltester::hash_slice(&Broken(1, 1), &Broken(1, 2), &Broken(2, 3)).unwrap_err();
+
+    assert!(matches!(error, HashError::HashSliceEqualButDifferentHashes));
+    assert!(!error.is_quality_issue());
+
+    let report = reltester::hash_slice_report(&Broken(1, 1), &Broken(1, 2), &Broken(2, 3))
+        .downgrade(HashError::is_quality_issue);
+    assert!(!report.is_ok());
+}
+
+/// Hashes slices by their address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pointer(u32);
+
+impl Hash for Pointer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        (data.as_ptr() as usize).hash(state);
+    }
+}
+
+#[test]
+fn hash_slice_override_hashes_the_address() {
+    let (a, b, c) = (Pointer(1), Pointer(2), Pointer(3));
+    assert!(matches!(
+        reltester::invariants::hash_slice_consistency_with_eq(&a, &b, &c),
+        Err(HashError::HashSliceEqualButDifferentHashes)
+    ));
+    assert!(matches!(
+        reltester::hash_slice(&a, &b, &c),
+        Err(HashError::HashSliceEqualButDifferentHashes)
+    ));
+}
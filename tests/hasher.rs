@@ -0,0 +1,227 @@
+use std::hash::{Hash, Hasher};
+
+use reltester::{
+    error::HashError,
+    hasher::{self, FnvHasher, HashWrite, HasherKind, RecordingHasher},
+};
+
+#[derive(Debug)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+    }
+}
+
+impl Eq for Point {}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
+#[test]
+fn hasher_records_typed_writes() {
+    let mut hasher = RecordingHasher::new();
+    (7u32, "ab", [1u8, 2]).hash(&mut hasher);
+
+    assert_eq!(
+        hasher.writes(),
+        &[
+            HashWrite::U32(7),
+            HashWrite::Bytes(b"ab".to_vec()),
+            HashWrite::U8(0xff),
+            HashWrite::Usize(2),
+            HashWrite::Bytes(vec![1, 2]),
+        ]
+    );
+    assert_eq!(
+        hasher.output(),
+        [
+            &7u32.to_ne_bytes()[..],
+            b"ab",
+            &[0xff],
+            &2usize.to_ne_bytes(),
+            &[1, 2],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn hasher_diff_points_to_first_divergent_write() {
+    let a = hasher::trace(&(1u32, 2u32, 3u32));
+    let b = hasher::trace(&(1u32, 5u32, 3u32));
+    let diff = hasher::diff(&a, &b).unwrap();
+
+    assert_eq!(diff.index(), 1);
+    assert_eq!(diff.a(), Some(&HashWrite::U32(2)));
+    assert_eq!(diff.to_string(), "writes[1]: write_u32(2) vs write_u32(5)");
+    assert!(hasher::diff(&a, &a).is_none());
+}
+
+#[test]
+fn hasher_diff_of_prefix_trace() {
+    let a = hasher::trace(&1u32);
+    let b = hasher::trace(&(1u32, 2u32));
+
+    assert_eq!(
+        hasher::diff(&a, &b).unwrap().to_string(),
+        "writes[1]: nothing vs write_u32(2)"
+    );
+}
+
+#[test]
+fn hasher_diff_attached_to_violation() {
+    let violation =
+        reltester::hash_verbose(&Point { x: 1, y: 2 }, &Point { x: 1, y: 3 }).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        HashError::EqualButDifferentHashes
+    ));
+    assert!(violation.observations().contains(&(
+        "first divergent write".to_string(),
+        "writes[1]: write_u32(2) vs write_u32(3)".to_string()
+    )));
+}
+
+#[quickcheck_macros::quickcheck]
+fn hasher_kinds_strings(a: String, b: String) -> bool {
+    reltester::hash_under(&a, &b, &HasherKind::ALL).is_ok()
+}
+
+/// A number which is stored compactly when small, but hashed as the same
+/// bytes either way.
+#[derive(Debug)]
+enum Compact {
+    Small(u8),
+    Big(u32),
+}
+
+impl Compact {
+    fn get(&self) -> u32 {
+        match *self {
+            Compact::Small(n) => u32::from(n),
+            Compact::Big(n) => n,
+        }
+    }
+}
+
+impl PartialEq for Compact {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for Compact {}
+
+impl Hash for Compact {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Compact::Small(n) => state.write(&u32::from(*n).to_ne_bytes()),
+            Compact::Big(n) => state.write_u32(*n),
+        }
+    }
+}
+
+#[test]
+fn hasher_kinds_expose_different_write_methods() {
+    let (a, b) = (Compact::Small(7), Compact::Big(7));
+    assert!(reltester::hash(&a, &b).is_ok());
+    assert!(reltester::hash_under(&a, &b, &[HasherKind::Default, HasherKind::Fnv]).is_ok());
+
+    let violation = reltester::hash_under(&a, &b, &HasherKind::ALL).unwrap_err();
+    assert!(matches!(
+        violation.error(),
+        HashError::EqualButDifferentHashes
+    ));
+    assert_eq!(
+        violation.observations()[0],
+        ("hasher".to_string(), "TypedHasher".to_string())
+    );
+}
+
+#[test]
+fn hasher_fnv_matches_reference() {
+    // Test vectors from the reference implementation.
+    assert_eq!(hash_with(FnvHasher::default(), b""), 0xcbf29ce484222325);
+    assert_eq!(hash_with(FnvHasher::default(), b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(
+        hash_with(FnvHasher::default(), b"foobar"),
+        0x85944171f73967e8
+    );
+}
+
+fn hash_with(mut hasher: impl Hasher, bytes: &[u8]) -> u64 {
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[quickcheck_macros::quickcheck]
+fn hasher_determinism_strings(a: Vec<String>) -> bool {
+    reltester::hash_determinism(&a).is_ok()
+}
+
+/// Hashes the address of its contents.
+#[derive(Clone)]
+struct ByAddress(std::rc::Rc<u32>);
+
+impl Hash for ByAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::rc::Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+#[test]
+fn hasher_determinism_pointer_addresses() {
+    let make = || ByAddress(std::rc::Rc::new(1));
+    assert!(reltester::hash_determinism(&make()).is_ok());
+
+    let violation = reltester::hash_determinism_with(make).unwrap_err();
+    assert!(matches!(violation.error(), HashError::DiffersFromCopy));
+    assert_eq!(violation.observations()[1].0, "hasher_output(make())");
+    assert!(violation.observations()[2]
+        .1
+        .starts_with("writes[0]: write_usize("));
+}
+
+/// Hashes its elements in iteration order.
+struct Unordered(std::collections::HashSet<u32>);
+
+impl Hash for Unordered {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for x in &self.0 {
+            x.hash(state);
+        }
+    }
+}
+
+#[test]
+fn hasher_determinism_hash_set_order() {
+    let violation = reltester::hash_determinism_with(|| Unordered((0..32).collect())).unwrap_err();
+    assert!(matches!(violation.error(), HashError::DiffersFromCopy));
+}
+
+/// Hashes a counter which is incremented on every call.
+struct Counter(std::cell::Cell<u32>);
+
+impl Hash for Counter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.set(self.0.get() + 1);
+        self.0.get().hash(state);
+    }
+}
+
+#[test]
+fn hasher_determinism_counter() {
+    let violation =
+        reltester::hash_determinism_with(|| Counter(std::cell::Cell::new(0))).unwrap_err();
+    assert!(matches!(violation.error(), HashError::Nondeterministic));
+}
@@ -0,0 +1,72 @@
+use quickcheck_macros::quickcheck;
+use reltester::error::MonotonicityError;
+
+#[quickcheck]
+fn monotone_widening(a: u32, b: u32) -> bool {
+    reltester::monotone(|x| u64::from(*x), &a, &b).is_ok()
+}
+
+#[quickcheck]
+fn strictly_monotone_big_endian_bytes(a: u32, b: u32) -> bool {
+    reltester::strictly_monotone(|x| x.to_be_bytes(), &a, &b).is_ok()
+}
+
+#[quickcheck]
+fn antitone_negation(a: i32, b: i32) -> bool {
+    reltester::antitone(|x| -i64::from(*x), &a, &b).is_ok()
+}
+
+#[test]
+fn little_endian_bytes_are_not_monotone() {
+    let violation = reltester::monotone_verbose(|x: &u32| x.to_le_bytes(), &1, &256).unwrap_err();
+
+    assert!(matches!(violation.error(), MonotonicityError::NotMonotone));
+    assert_eq!(
+        violation.observations(),
+        &[
+            ("a <= b".to_string(), "true".to_string()),
+            ("f(a) <= f(b)".to_string(), "false".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn monotone_checks_both_orders() {
+    let violation = reltester::monotone_verbose(|x: &u32| x.to_le_bytes(), &256, &1).unwrap_err();
+
+    assert_eq!(violation.observations()[0].0, "b <= a");
+    assert_eq!(
+        violation.values()[2],
+        ("f(a)".to_string(), "[0, 1, 0, 0]".to_string())
+    );
+}
+
+#[test]
+fn saturation_is_monotone_but_not_strictly() {
+    let saturate = |x: &u32| (*x).min(10);
+
+    assert!(reltester::monotone(saturate, &20, &30).is_ok());
+    assert!(matches!(
+        reltester::strictly_monotone(saturate, &20, &30),
+        Err(MonotonicityError::NotStrictlyMonotone)
+    ));
+    assert!(matches!(
+        reltester::antitone(saturate, &1, &2),
+        Err(MonotonicityError::NotAntitone)
+    ));
+}
+
+/// Has no [`Debug`](std::fmt::Debug) implementation.
+#[derive(PartialEq, PartialOrd)]
+struct Opaque(u32);
+
+#[test]
+fn monotone_without_debug() {
+    let reverse = |x: &Opaque| Opaque(u32::MAX - x.0);
+
+    assert!(reltester::antitone(reverse, &Opaque(1), &Opaque(2)).is_ok());
+    assert!(matches!(
+        reltester::monotone(reverse, &Opaque(2), &Opaque(1)),
+        Err(MonotonicityError::NotMonotone)
+    ));
+}
@@ -0,0 +1,111 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use quickcheck_macros::quickcheck;
+use reltester::error::{HashError, OrdError, PartialEqError};
+
+/// A version string which is compared, checked for equality, and hashed
+/// component-wise without allocating.
+#[derive(Debug)]
+struct Version(String);
+
+impl Version {
+    fn components(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.')
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.components().eq(other.components())
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.components().cmp(other.components())
+    }
+}
+
+impl Hash for Version {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for component in self.components() {
+            component.hash(state);
+        }
+    }
+}
+
+/// The old, allocating implementations.
+fn old_components(version: &Version) -> Vec<String> {
+    version.0.split('.').map(str::to_string).collect()
+}
+
+#[quickcheck]
+fn oracle_ord(a: String, b: String) -> bool {
+    reltester::ord_agrees_with(&Version(a), &Version(b), |a, b| {
+        old_components(a).cmp(&old_components(b))
+    })
+    .is_ok()
+}
+
+#[quickcheck]
+fn oracle_partial_eq(a: String, b: String) -> bool {
+    reltester::partial_eq_agrees_with(&Version(a), &Version(b), |a, b| {
+        old_components(a) == old_components(b)
+    })
+    .is_ok()
+}
+
+#[quickcheck]
+fn oracle_hash(a: String) -> bool {
+    reltester::hash_agrees_with(&Version(a), |a, mut state| {
+        for component in old_components(a) {
+            component.hash(&mut state);
+        }
+    })
+    .is_ok()
+}
+
+#[test]
+fn oracle_ord_catches_numeric_order() {
+    let (a, b) = (Version("1.10".to_string()), Version("1.9".to_string()));
+    let numeric =
+        |v: &Version| -> Vec<u32> { v.components().map(|c| c.parse().unwrap()).collect() };
+
+    assert!(matches!(
+        reltester::ord_agrees_with(&a, &b, |a, b| numeric(a).cmp(&numeric(b))),
+        Err(OrdError::DisagreesWithOracle)
+    ));
+}
+
+#[test]
+fn oracle_partial_eq_catches_string_equality() {
+    assert!(matches!(
+        reltester::partial_eq_agrees_with(
+            &Version("1.0".to_string()),
+            &Version("2.0".to_string()),
+            |a, b| a.0.len() == b.0.len()
+        ),
+        Err(PartialEqError::DisagreesWithOracle)
+    ));
+}
+
+#[test]
+fn oracle_hash_catches_joined_hash() {
+    assert!(matches!(
+        reltester::hash_agrees_with(&Version("1.0".to_string()), |a, mut state| {
+            a.0.hash(&mut state)
+        }),
+        Err(HashError::DisagreesWithOracle)
+    ));
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+
+use quickcheck_macros::quickcheck;
+use reltester::{
+    error::{Error, OrdError},
+    invariants,
+};
+
+#[quickcheck]
+fn ord_methods_exactness_u32(a: u32, b: u32, c: u32) -> bool {
+    invariants::ord_methods_exactness(&a, &b, &c).is_ok()
+        && reltester::ord_exact(&a, &b, &c).is_ok()
+}
+
+#[quickcheck]
+fn ord_methods_exactness_strings(a: String, b: String, c: String) -> bool {
+    invariants::ord_methods_exactness(&a, &b, &c).is_ok()
+}
+
+/// Tasks are ordered by priority alone, so tasks with different names may
+/// compare equal.
+#[derive(Debug, Clone)]
+struct Task {
+    priority: u8,
+    name: &'static str,
+}
+
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for Task {}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.priority > self.priority {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+#[test]
+fn ord_max_returns_the_wrong_argument_on_ties() {
+    let a = Task {
+        priority: 1,
+        name: "a",
+    };
+    let b = Task {
+        priority: 1,
+        name: "b",
+    };
+
+    assert!(matches!(
+        invariants::ord_methods_exactness(&a, &b, &b),
+        Err(OrdError::MaxDiffersFromDefault)
+    ));
+    assert!(matches!(
+        reltester::ord_exact(&a, &b, &b).unwrap_err().error(),
+        Error::Ord(OrdError::MaxDiffersFromDefault)
+    ));
+}
+
+#[test]
+fn ord_clamp_does_not_panic() {
+    let task = |priority| Task {
+        priority,
+        name: "task",
+    };
+
+    assert!(matches!(
+        invariants::ord_methods_exactness(&task(1), &task(1), &task(2)),
+        Err(OrdError::ClampDidNotPanic)
+    ));
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct AlwaysLess;
+
+impl PartialOrd for AlwaysLess {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AlwaysLess {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Less
+    }
+}
+
+#[test]
+fn ord_cmp_antisymmetry() {
+    assert!(matches!(
+        invariants::ord_cmp_antisymmetry(&AlwaysLess, &AlwaysLess),
+        Err(OrdError::BrokeCmpAntisymmetry)
+    ));
+}
+
+#[quickcheck]
+fn ord_matches_key_tuples(a: (u8, String), b: (u8, String)) -> bool {
+    reltester::ord_matches_key(&a, &b, |x| (x.0, x.1.clone())).is_ok()
+}
+
+#[test]
+fn ord_matches_key_task_ignores_name() {
+    let a = Task {
+        priority: 1,
+        name: "a",
+    };
+    let b = Task {
+        priority: 1,
+        name: "b",
+    };
+
+    assert!(reltester::ord_matches_key(&a, &b, |t| t.priority).is_ok());
+    assert!(matches!(
+        reltester::ord_matches_key(&a, &b, |t| (t.priority, t.name)),
+        Err(OrdError::DisagreesWithKey)
+    ));
+    assert!(reltester::eq_matches_key(&a, &b, |t| (t.priority, t.name)).is_err());
+    assert!(reltester::partial_ord_matches_key(&a, &b, |t| t.priority).is_ok());
+}
@@ -0,0 +1,23 @@
+//! The panic hook is process-wide, so this lives in its own test binary.
+
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static PANICS: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn clamp_check_leaves_panic_hook_alone() {
+    panic::set_hook(Box::new(|_| {
+        PANICS.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    // Checks that `clamp` panics if `min > max`, which reaches the hook.
+    assert!(reltester::ord_exact(&1u32, &2, &3).is_ok());
+    let panics = PANICS.load(Ordering::SeqCst);
+    assert!(panics > 0);
+
+    assert!(panic::catch_unwind(|| panic!("still hooked")).is_err());
+    assert_eq!(PANICS.load(Ordering::SeqCst), panics + 1);
+}
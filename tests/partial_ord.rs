@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+
+use quickcheck_macros::quickcheck;
+use reltester::{error::PartialOrdError, invariants};
+
+#[quickcheck]
+fn partial_ord_f64(a: f64, b: f64, c: f64) -> bool {
+    reltester::partial_ord(&a, &b, &c).is_ok()
+}
+
+/// Claims to be smaller than everything, itself included.
+#[derive(Debug, PartialEq)]
+struct Bottom;
+
+impl PartialOrd for Bottom {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+
+    fn lt(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn partial_ord_irreflexivity() {
+    assert!(matches!(
+        invariants::partial_ord_irreflexivity(&Bottom),
+        Err(PartialOrdError::BrokeIrreflexivity)
+    ));
+    assert!(matches!(
+        invariants::partial_ord_asymmetry(&Bottom, &Bottom),
+        Err(PartialOrdError::BrokeAsymmetry)
+    ));
+}
+
+/// Orders by the first field only, but compares both fields for equality.
+#[derive(Debug, PartialEq)]
+struct FirstField(u8, u8);
+
+impl PartialOrd for FirstField {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+
+    fn le(&self, other: &Self) -> bool {
+        self.0 <= other.0
+    }
+
+    fn ge(&self, other: &Self) -> bool {
+        self.0 >= other.0
+    }
+}
+
+#[test]
+fn partial_ord_antisymmetry() {
+    let (a, b) = (FirstField(1, 2), FirstField(1, 3));
+    assert!(matches!(
+        invariants::partial_ord_antisymmetry(&a, &b),
+        Err(PartialOrdError::BrokeAntisymmetry)
+    ));
+    assert!(reltester::partial_ord_report(&a, &b, &a)
+        .violations()
+        .iter()
+        .any(|v| matches!(
+            v.error(),
+            reltester::error::Error::PartiaOrd(PartialOrdError::BrokeAntisymmetry)
+        )));
+}
+
+/// Wrapper whose `==` ignores the field that `<` looks at.
+#[derive(Debug)]
+struct Versioned {
+    id: u32,
+    version: u32,
+}
+
+impl PartialEq for Versioned {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl PartialOrd for Versioned {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.id.cmp(&other.id) {
+            Ordering::Equal if self.version != other.version => None,
+            ordering => Some(ordering),
+        }
+    }
+
+    fn lt(&self, other: &Self) -> bool {
+        (self.id, self.version) < (other.id, other.version)
+    }
+}
+
+#[test]
+fn partial_ord_mixed_transitivity() {
+    let a = Versioned { id: 1, version: 2 };
+    let b = Versioned { id: 1, version: 3 };
+    let c = Versioned { id: 1, version: 0 };
+
+    assert!(invariants::partial_eq_transitivity(&a, &b, &c).is_ok());
+    assert!(matches!(
+        invariants::partial_ord_transitivity(&a, &b, &c),
+        Err(PartialOrdError::BrokeMixedTransitivity)
+    ));
+
+    let violation = reltester::partial_ord_report(&a, &b, &c)
+        .into_violations()
+        .into_iter()
+        .find(|v| {
+            matches!(
+                v.error(),
+                reltester::error::Error::PartiaOrd(PartialOrdError::BrokeMixedTransitivity)
+            )
+        })
+        .unwrap();
+    assert_eq!(
+        violation.observations(),
+        &[
+            ("a < b".to_string(), "true".to_string()),
+            ("b == c".to_string(), "true".to_string()),
+            ("a < c".to_string(), "false".to_string())
+        ]
+    );
+}
+
+/// Even values consider themselves equivalent to anything, odd values are
+/// incomparable.
+#[derive(Debug, PartialEq)]
+struct OneSided(u8);
+
+impl PartialOrd for OneSided {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        if self.0 % 2 == 0 {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn partial_ord_incomparability_is_symmetric() {
+    assert!(matches!(
+        invariants::partial_ord_duality(&OneSided(2), &OneSided(3)),
+        Err(PartialOrdError::BrokeIncomparabilitySymmetry)
+    ));
+    assert!(invariants::partial_ord_duality(&OneSided(3), &OneSided(5)).is_ok());
+}
+
+#[derive(Debug, PartialEq)]
+struct Backwards(u8);
+
+impl PartialOrd for Backwards {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+
+    fn gt(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+#[test]
+fn partial_ord_duality_checks_both_sides() {
+    // `a < b` and `b > a` disagree, but `a > b` and `b < a` agree.
+    assert!(matches!(
+        invariants::partial_ord_duality(&Backwards(1), &Backwards(2)),
+        Err(PartialOrdError::BrokeDuality)
+    ));
+}
+
+/// Incomparable values, which nonetheless claim to be equal.
+#[derive(Debug)]
+struct Shrug;
+
+impl PartialEq for Shrug {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for Shrug {
+    fn partial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        None
+    }
+}
+
+#[test]
+fn partial_ord_incomparable_values_are_not_equal() {
+    assert!(matches!(
+        invariants::partial_ord_incomparability(&Shrug, &Shrug),
+        Err(PartialOrdError::BadIncomparable)
+    ));
+    assert!(invariants::partial_ord_incomparability(&f32::NAN, &1.0).is_ok());
+}
+
+#[quickcheck]
+fn strict_weak_order_u32(a: u32, b: u32, c: u32) -> bool {
+    reltester::strict_weak_order(&a, &b, &c).is_ok()
+}
+
+#[test]
+fn strict_weak_order_f64_nan() {
+    assert!(reltester::partial_ord(&1.0, &f64::NAN, &2.0).is_ok());
+    assert!(matches!(
+        reltester::strict_weak_order(&1.0, &f64::NAN, &2.0),
+        Err(reltester::error::Error::PartiaOrd(
+            PartialOrdError::BrokeIncomparabilityTransitivity
+        ))
+    ));
+}
+
+#[test]
+fn strict_weak_order_f64_nan_in_any_position() {
+    for (a, b, c) in [
+        (f64::NAN, 1.0, 2.0),
+        (1.0, f64::NAN, 2.0),
+        (1.0, 2.0, f64::NAN),
+    ] {
+        assert!(matches!(
+            reltester::strict_weak_order(&a, &b, &c),
+            Err(reltester::error::Error::PartiaOrd(
+                PartialOrdError::BrokeIncomparabilityTransitivity
+            ))
+        ));
+    }
+}
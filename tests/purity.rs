@@ -0,0 +1,99 @@
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use quickcheck_macros::quickcheck;
+use reltester::{
+    error::{Error, HashError, OrdError, PartialEqError, PartialOrdError},
+    invariants,
+};
+
+#[quickcheck]
+fn purity_strings(a: String, b: String) -> bool {
+    invariants::partial_eq_purity(&a, &b).is_ok()
+        && invariants::partial_ord_purity(&a, &b).is_ok()
+        && invariants::ord_purity(&a, &b).is_ok()
+        && invariants::hash_purity(&a, &b).is_ok()
+}
+
+/// Counts how many times it was compared, and (incorrectly) takes the count
+/// into account.
+#[derive(Debug, Default)]
+struct Flaky {
+    value: u32,
+    calls: Cell<u32>,
+}
+
+impl Flaky {
+    fn key(&self) -> u32 {
+        self.calls.set(self.calls.get() + 1);
+        self.value + self.calls.get() / 4
+    }
+}
+
+impl PartialEq for Flaky {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Flaky {}
+
+impl PartialOrd for Flaky {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Flaky {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Hash for Flaky {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+fn flaky(value: u32) -> Flaky {
+    Flaky {
+        value,
+        ..Flaky::default()
+    }
+}
+
+#[test]
+fn purity_flaky_is_incorrect() {
+    assert!(matches!(
+        invariants::partial_eq_purity(&flaky(1), &flaky(1)),
+        Err(PartialEqError::Impure)
+    ));
+    assert!(matches!(
+        invariants::partial_ord_purity(&flaky(1), &flaky(1)),
+        Err(PartialOrdError::Impure)
+    ));
+    assert!(matches!(
+        invariants::ord_purity(&flaky(1), &flaky(1)),
+        Err(OrdError::Impure)
+    ));
+    assert!(matches!(
+        invariants::hash_purity(&flaky(1), &flaky(1)),
+        Err(HashError::Impure)
+    ));
+}
+
+#[test]
+fn purity_is_checked_first() {
+    let violation = reltester::ord_verbose(&flaky(1), &flaky(1), &flaky(1)).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        Error::PartialEq(PartialEqError::Impure)
+    ));
+    assert_eq!(violation.observations()[0].0, "a == b");
+    assert_eq!(violation.observations()[1].0, "a == b again");
+}
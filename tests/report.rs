@@ -0,0 +1,114 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use reltester::error::{Error, HashError, OrdError, PartialEqError};
+
+#[derive(Debug)]
+struct Weird(u8);
+
+impl PartialEq for Weird {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    #[allow(clippy::partialeq_ne_impl)]
+    fn ne(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+impl Eq for Weird {}
+
+impl PartialOrd for Weird {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+impl Ord for Weird {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+#[test]
+fn report_collects_all_violations() {
+    let report = reltester::ord_report(&Weird(1), &Weird(2), &Weird(3));
+    let errors = report
+        .violations()
+        .iter()
+        .map(|v| v.error().clone())
+        .collect::<Vec<_>>();
+
+    assert!(!report.is_ok());
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            Error::PartialEq(PartialEqError::BadNe),
+            Error::Ord(OrdError::BadCmp)
+        ]
+    ));
+    assert!(report.violations().iter().all(|v| v.values().is_empty()));
+    assert_eq!(
+        reltester::ord_verbose(&Weird(1), &Weird(2), &Weird(3))
+            .unwrap_err()
+            .values()[0],
+        ("a".to_string(), "Weird(1)".to_string())
+    );
+}
+
+#[test]
+fn report_first_violation_matches_fail_fast() {
+    let (a, b, c) = (Weird(1), Weird(2), Weird(3));
+    let first = reltester::ord_report(&a, &b, &c).into_result().unwrap_err();
+
+    assert_eq!(
+        first.error().to_string(),
+        reltester::ord(&a, &b, &c).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn report_downgrade_moves_violations_to_warnings() {
+    let report = reltester::ord_report(&Weird(1), &Weird(2), &Weird(3))
+        .downgrade(|e| matches!(e, Error::PartialEq(_)));
+
+    assert_eq!(report.violations().len(), 1);
+    assert_eq!(report.warnings().len(), 1);
+    assert!(report.to_string().contains("1 warning(s):"));
+
+    let report = report.downgrade(|_| true);
+    assert!(report.is_ok());
+    assert!(report
+        .to_string()
+        .starts_with("No invariants were broken\n2 warning(s):"));
+}
+
+#[test]
+fn report_is_ok_for_correct_impls() {
+    assert!(reltester::ord_report(&1u32, &2, &3).is_ok());
+    assert!(reltester::partial_ord_report(&f32::NAN, &1.0, &2.0).is_ok());
+    assert!(reltester::hash_report("foo", "bar").is_ok());
+    assert!(reltester::double_ended_iterator_report([1, 2, 3].iter()).is_ok());
+}
+
+/// Doesn't implement `Debug`, and hashes all values the same.
+#[derive(PartialEq, Eq)]
+struct Opaque(u8);
+
+impl Hash for Opaque {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[test]
+fn report_does_not_require_debug() {
+    let report = reltester::hash_report(&Opaque(1), &Opaque(2));
+    assert!(!report.is_ok());
+
+    let report = report.downgrade(HashError::is_quality_issue);
+    assert!(report.is_ok());
+    assert_eq!(report.warnings().len(), 1);
+    assert!(reltester::eq_report(&Opaque(1), &Opaque(2), &Opaque(3)).is_ok());
+}
@@ -0,0 +1,276 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use quickcheck_macros::quickcheck;
+
+#[quickcheck]
+fn sample_u32(x: Vec<u32>) -> bool {
+    reltester::ord_sample(&x).is_ok() && reltester::hash_sample(&x).is_ok()
+}
+
+#[quickcheck]
+fn sample_f32(x: Vec<f32>) -> bool {
+    reltester::partial_ord_sample(&x).is_ok()
+}
+
+#[quickcheck]
+fn sample_strings(x: Vec<String>) -> bool {
+    reltester::eq_sample(&x).is_ok()
+        && reltester::ord_sample(&x).is_ok()
+        && reltester::hash_sample(&x).is_ok()
+}
+
+#[test]
+fn sample_large() {
+    let x = (0..50_000u64)
+        .map(|n| (n * 7919) % 1000)
+        .collect::<Vec<_>>();
+    assert!(reltester::ord_sample(&x).is_ok());
+    assert!(reltester::hash_sample(&x).is_ok());
+}
+
+#[test]
+fn sample_large_distinct() {
+    let x = (0..2_000u32)
+        .map(|n| n.wrapping_mul(2_654_435_761))
+        .collect::<Vec<_>>();
+    assert!(reltester::hash_sample(&x).is_ok());
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Hand::*;
+
+        match (self, other) {
+            (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => Ordering::Greater,
+            (Scissors, Rock) | (Rock, Paper) | (Paper, Scissors) => Ordering::Less,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+#[test]
+fn sample_rock_paper_scissors_is_not_transitive() {
+    let hands = [Hand::Rock, Hand::Paper, Hand::Scissors].repeat(100);
+    let violation = reltester::ord_sample(&hands).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::PartiaOrd(reltester::error::PartialOrdError::BrokeTransitivity)
+    ));
+}
+
+#[derive(Debug)]
+struct Approx(f64);
+
+impl PartialEq for Approx {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() < 0.1
+    }
+}
+
+#[test]
+fn sample_approximate_equality_is_not_transitive() {
+    let x = (0..1000)
+        .map(|n| Approx(n as f64 * 0.01))
+        .collect::<Vec<_>>();
+    let violation = reltester::eq_sample(&x).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::PartialEq(reltester::error::PartialEqError::BrokeTransitivity)
+    ));
+    assert_eq!(violation.values().len(), 3);
+}
+
+#[derive(Debug)]
+struct CaseInsensitive(&'static str);
+
+impl PartialEq for CaseInsensitive {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(other.0)
+    }
+}
+
+impl Eq for CaseInsensitive {}
+
+impl Hash for CaseInsensitive {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+#[test]
+fn sample_large_case_insensitive_hash_is_incorrect() {
+    let x = (0..200)
+        .map(|i| format!("k{}", i))
+        .chain(Some("K7".to_string()))
+        .map(|key| CaseInsensitive(Box::leak(key.into_boxed_str())))
+        .collect::<Vec<_>>();
+    let violation = reltester::hash_sample(&x).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::HashError::EqualButDifferentHashes
+    ));
+    let mut values = violation
+        .values()
+        .iter()
+        .map(|(_, value)| value.as_str())
+        .collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(
+        values,
+        ["CaseInsensitive(\"K7\")", "CaseInsensitive(\"k7\")"]
+    );
+}
+
+#[test]
+fn sample_case_insensitive_hash_is_incorrect() {
+    let x = ["foo", "bar", "Baz", "FOO", "qux"].map(CaseInsensitive);
+    let violation = reltester::hash_sample(&x).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::HashError::EqualButDifferentHashes
+    ));
+    let mut values = violation
+        .values()
+        .iter()
+        .map(|(_, value)| value.as_str())
+        .collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(
+        values,
+        ["CaseInsensitive(\"FOO\")", "CaseInsensitive(\"foo\")"]
+    );
+}
+
+/// Writes the bytes of the string without a terminator.
+#[derive(Debug, PartialEq, Eq)]
+struct Unterminated(&'static str);
+
+impl Hash for Unterminated {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.0.as_bytes());
+    }
+}
+
+#[test]
+fn sample_unterminated_hash_has_prefix_collisions() {
+    let x = ["zz", "foobar", "bar", "foo", "qux", "b"].map(Unterminated);
+    let violation = reltester::hash_sample(&x).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::HashError::PrefixCollision
+    ));
+}
+
+#[test]
+fn cycles_u32() {
+    let x = (0..100u32).map(|n| n % 10).collect::<Vec<_>>();
+    assert!(reltester::partial_ord_cycles(&x).is_ok());
+}
+
+#[test]
+fn cycles_f32_with_nan() {
+    assert!(reltester::partial_ord_cycles(&[1.0, f32::NAN, -0.0, 0.0, 1.0]).is_ok());
+}
+
+#[test]
+fn cycles_rock_paper_scissors() {
+    let hands = [Hand::Rock, Hand::Paper, Hand::Paper, Hand::Scissors];
+    let violation = reltester::partial_ord_cycles(&hands).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::PartialOrdError::Cycle
+    ));
+    assert_eq!(violation.values().len(), 3);
+}
+
+/// Orders each digit right before its successor modulo 10, so that `9 < 0`
+/// closes a cycle. Only chains of ten values can expose this.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Clock(u8);
+
+impl PartialOrd for Clock {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.0 == other.0 {
+            Some(Ordering::Equal)
+        } else if (self.0 + 1) % 10 == other.0 {
+            Some(Ordering::Less)
+        } else if (other.0 + 1) % 10 == self.0 {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn cycles_finds_the_shortest_long_cycle() {
+    let x = (0..30).rev().map(|n| Clock(n % 10)).collect::<Vec<_>>();
+    let violation = reltester::partial_ord_cycles(&x).unwrap_err();
+
+    assert_eq!(violation.values().len(), 10);
+    assert_eq!(
+        violation.observations()[0],
+        ("a < b".to_string(), "true".to_string())
+    );
+    assert_eq!(
+        violation.observations()[9],
+        ("j < a".to_string(), "true".to_string())
+    );
+}
+
+#[quickcheck]
+fn classes_strings(x: Vec<String>) -> bool {
+    reltester::eq_classes(&x).is_ok()
+}
+
+#[test]
+fn classes_nan_is_not_reflexive() {
+    let violation = reltester::eq_classes(&[1.0, f64::NAN]).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::Eq(reltester::error::EqError::BrokeReflexivity)
+    ));
+}
+
+#[test]
+fn classes_approximate_equality_chain() {
+    let x = [0.0, 0.3, 0.06, 0.12, 0.18, 0.24].map(Approx);
+    let violation = reltester::eq_classes(&x).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::PartialEq(reltester::error::PartialEqError::BrokeTransitivity)
+    ));
+    assert_eq!(violation.values()[0].1, "Approx(0.0)");
+    assert_eq!(violation.values()[5].1, "Approx(0.3)");
+    assert_eq!(
+        violation.observations().last().unwrap(),
+        &(
+            "sample indices".to_string(),
+            "[0, 2, 3, 4, 5, 1]".to_string()
+        )
+    );
+}
@@ -0,0 +1,66 @@
+use std::cmp::Ordering;
+
+#[test]
+fn verbose_nan_is_not_reflexive() {
+    let violation = reltester::eq_verbose(&f64::NAN, &1.0, &2.0).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::Eq(reltester::error::EqError::BrokeReflexivity)
+    ));
+    assert_eq!(
+        violation.values(),
+        &[
+            ("a".to_string(), "NaN".to_string()),
+            ("b".to_string(), "1.0".to_string()),
+            ("c".to_string(), "2.0".to_string())
+        ]
+    );
+    assert_eq!(
+        violation.observations(),
+        &[("a == a".to_string(), "false".to_string())]
+    );
+}
+
+#[derive(Debug)]
+struct Fuzzy(i32);
+
+impl PartialEq for Fuzzy {
+    fn eq(&self, other: &Self) -> bool {
+        (self.0 - other.0).abs() <= 1
+    }
+}
+
+impl PartialOrd for Fuzzy {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+}
+
+#[test]
+fn verbose_fuzzy_equality_is_not_transitive() {
+    let violation = reltester::partial_ord_verbose(&Fuzzy(0), &Fuzzy(1), &Fuzzy(2)).unwrap_err();
+
+    assert!(matches!(
+        violation.error(),
+        reltester::error::Error::PartialEq(reltester::error::PartialEqError::BrokeTransitivity)
+    ));
+    assert_eq!(
+        violation.values()[2],
+        ("c".to_string(), "Fuzzy(2)".to_string())
+    );
+    assert_eq!(
+        violation.to_string(),
+        "a == b && b == c MUST imply a == c\n    a = Fuzzy(0)\n    b = Fuzzy(1)\n    c = Fuzzy(2)\n    where a == b: true, b == c: true, a == c: false"
+    );
+}
+
+#[test]
+fn verbose_is_ok_for_correct_impls() {
+    assert!(reltester::ord_verbose(&1u32, &2, &3).is_ok());
+    assert!(reltester::hash_verbose("foo", "bar").is_ok());
+}